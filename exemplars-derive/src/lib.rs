@@ -0,0 +1,502 @@
+//! Derive and attribute macros for the `exemplars` crate.
+//!
+//! Use it through the `derive` feature of `exemplars` instead of depending on
+//! this crate directly.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Expr, ExprArray, Field,
+    Fields, FnArg, Generics, ItemFn, Path, ReturnType, Type, Visibility,
+};
+
+/// Derive `Exemplars` for a struct or an enum.
+///
+/// Every field type must implement `Exemplars`. A struct combines the
+/// exemplars of its fields the same way a tuple does, so every combination of
+/// field values shows up at a finite position, even when some field streams
+/// are infinite. The primary exemplar is built from the primary exemplar of
+/// every field.
+///
+/// An enum takes one exemplar from each variant in turn, so the first few
+/// values already cover every variant. Variants are built the same way as
+/// structs. The first variant supplies the primary exemplar unless another one
+/// is marked with `#[exemplars(primary)]`. Variants marked with
+/// `#[exemplars(skip)]` are never produced, which is useful for recursive
+/// payloads or payload types without an `Exemplars` impl.
+///
+/// A field's exemplars can be overridden with field attributes:
+///
+/// - `#[exemplars(values = [a, b, c])]` uses the listed values, the first one
+///   being the primary;
+/// - `#[exemplars(with = path::to_fn)]` calls a function returning
+///   `impl IntoIterator<Item = FieldType>`, the first value being the primary;
+/// - `#[exemplars(primary = expr)]` puts `expr` in front of the field's other
+///   exemplars, making it the primary. It is left out of the values that
+///   follow, so the field type must implement `PartialEq`, and it is evaluated
+///   once and cloned, so the field type must implement `Clone`.
+///
+/// `primary` can be combined with either `values` or `with`. The field type
+/// only needs an `Exemplars` impl when neither `values` nor `with` is given.
+///
+/// The `values` and `primary` expressions must have exactly the field type,
+/// so a `String` field takes `values = [String::from("a@example.com")]`.
+///
+/// `CARDINALITY` is derived as well: a struct has the product of its field
+/// stream lengths and an enum has the sum of its variants' exemplars.
+/// Streams from `with` functions count as `AtLeast(1)`, and `primary` turns a
+/// count of `Finite(n)` into `AtLeast(n)`, as the value may or may not be one
+/// of the `n`.
+#[proc_macro_derive(Exemplars, attributes(exemplars))]
+pub fn derive_exemplars(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Run a test once for each of the first exemplars of its parameter types.
+///
+/// The test function takes one or more parameters whose types implement
+/// `Exemplars` and `Debug`. With several parameters, the test runs over the
+/// exemplars of the tuple of their types, which combines them fairly. The
+/// number of runs is limited to 100 unless set with `limit` or with the
+/// `EXEMPLARS_LIMIT` environment variable, which takes precedence:
+///
+/// ```ignore
+/// #[exemplar_test(limit = 500)]
+/// fn parses_what_it_prints(value: u32, padded: bool) {
+///     // ...
+/// }
+/// ```
+///
+/// When a run panics, the test fails with the position and the `Debug` output
+/// of the failing exemplar. Attributes on the function, like `#[ignore]`, are
+/// kept on the generated test.
+#[proc_macro_attribute]
+pub fn exemplar_test(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut limit = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("limit") {
+            limit = Some(meta.value()?.parse::<Expr>()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `limit`"))
+        }
+    });
+    parse_macro_input!(attr with parser);
+    let item = parse_macro_input!(item as ItemFn);
+    expand_test(limit, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_test(limit: Option<Expr>, mut item: ItemFn) -> syn::Result<TokenStream> {
+    let attrs = std::mem::take(&mut item.attrs);
+    let sig = &item.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "#[exemplar_test] does not support async functions",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            "#[exemplar_test] does not support generic functions",
+        ));
+    }
+    if let ReturnType::Type(..) = &sig.output {
+        return Err(syn::Error::new_spanned(
+            &sig.output,
+            "#[exemplar_test] functions must not return a value",
+        ));
+    }
+    let types = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            FnArg::Typed(pat) => Ok(&*pat.ty),
+            FnArg::Receiver(receiver) => Err(syn::Error::new_spanned(
+                receiver,
+                "#[exemplar_test] functions cannot take `self`",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    if types.is_empty() || types.len() > 12 {
+        return Err(syn::Error::new_spanned(
+            &sig.inputs,
+            "#[exemplar_test] functions must take between 1 and 12 parameters",
+        ));
+    }
+
+    let vis = &item.vis;
+    let name = &item.sig.ident;
+    let limit = limit.unwrap_or_else(|| parse_quote!(::exemplars::__private::DEFAULT_LIMIT));
+    let args: Vec<_> = (0..types.len())
+        .map(|i| format_ident!("__exemplars_arg_{}", i))
+        .collect();
+    let (ty, pattern) = if let [ty] = types[..] {
+        (quote!(#ty), quote!(#(#args)*))
+    } else {
+        (quote!((#(#types,)*)), quote!((#(#args,)*)))
+    };
+    let mut test = item.clone();
+    test.sig.ident = format_ident!("__exemplars_test");
+    test.vis = Visibility::Inherited;
+
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #name() {
+            #test
+            ::exemplars::__private::run::<#ty>(#limit, |#pattern| __exemplars_test(#(#args),*));
+        }
+    })
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // `exemplar()` keeps its default, so that `primary` expressions are only
+    // evaluated in `exemplars()`.
+    let (exemplars, cardinality) = match &input.data {
+        Data::Struct(data) => (
+            fields_exemplars(&quote!(Self), &data.fields)?,
+            fields_cardinality(&data.fields)?,
+        ),
+        Data::Enum(data) => enum_exemplars(name, data)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "#[derive(Exemplars)] does not support unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::exemplars::Exemplars for #name #ty_generics #where_clause {
+            const CARDINALITY: ::exemplars::Cardinality = #cardinality;
+
+            fn exemplars() -> impl ::core::iter::IntoIterator<Item = Self> {
+                #exemplars
+            }
+        }
+    })
+}
+
+/// Options set with `#[exemplars(..)]` on an enum variant.
+#[derive(Default)]
+struct VariantAttrs {
+    primary: bool,
+    skip: bool,
+}
+
+impl VariantAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("exemplars"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("primary") {
+                    result.primary = true;
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+                } else {
+                    return Err(meta.error("expected `primary` or `skip`"));
+                }
+                Ok(())
+            })?;
+            if result.primary && result.skip {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a variant cannot be both `primary` and `skip`",
+                ));
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// The `exemplars()` body and the `CARDINALITY` for an enum.
+///
+/// Variant streams are polled round-robin, starting with the primary variant.
+fn enum_exemplars(name: &syn::Ident, data: &DataEnum) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut primary = None;
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let attrs = VariantAttrs::parse(&variant.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        if attrs.primary {
+            if primary.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "only one variant can be marked `#[exemplars(primary)]`",
+                ));
+            }
+            primary = Some(variants.len());
+        }
+        variants.push(variant);
+    }
+    if variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "#[derive(Exemplars)] needs at least one variant that is not skipped",
+        ));
+    }
+    if let Some(primary) = primary {
+        let variant = variants.remove(primary);
+        variants.insert(0, variant);
+    }
+
+    let paths: Vec<_> = variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            quote!(Self::#ident)
+        })
+        .collect();
+    let streams: Vec<_> = (0..variants.len())
+        .map(|i| format_ident!("__exemplars_variant_{}", i))
+        .collect();
+    let stream_exprs = paths
+        .iter()
+        .zip(&variants)
+        .map(|(path, variant)| fields_exemplars(path, &variant.fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let indices = 0..variants.len();
+    let count = variants.len();
+
+    let exemplars = quote! {{
+        #(let mut #streams = #stream_exprs;)*
+        let mut __exemplars_exhausted = [false; #count];
+        let mut __exemplars_turn = 0usize;
+        ::core::iter::from_fn(move || {
+            let __exemplars_order = (__exemplars_turn..#count).chain(0..__exemplars_turn);
+            for __exemplars_current in __exemplars_order {
+                if __exemplars_exhausted[__exemplars_current] {
+                    continue;
+                }
+                let __exemplars_next = match __exemplars_current {
+                    #(#indices => ::core::iter::Iterator::next(&mut #streams),)*
+                    _ => ::core::unreachable!(),
+                };
+                if let ::core::option::Option::Some(__exemplars_value) = __exemplars_next {
+                    __exemplars_turn = __exemplars_current + 1;
+                    return ::core::option::Option::Some(__exemplars_value);
+                }
+                __exemplars_exhausted[__exemplars_current] = true;
+            }
+            ::core::option::Option::None
+        })
+    }};
+    let cardinalities = variants
+        .iter()
+        .map(|variant| fields_cardinality(&variant.fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let cardinality = quote! {
+        ::exemplars::Cardinality::Finite(0)
+            #(.saturating_add(#cardinalities))*
+    };
+    Ok((exemplars, cardinality))
+}
+
+/// Require `Exemplars` for every type parameter.
+///
+/// Lifetime parameters must be `'static`, since borrowed exemplars come from
+/// `StaticExemplars`.
+fn add_trait_bounds(mut generics: Generics) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::exemplars::Exemplars));
+    }
+    for param in generics.lifetimes_mut() {
+        param.bounds.push(parse_quote!('static));
+    }
+    generics
+}
+
+/// An expression building `path` from the given field values.
+fn construct(
+    path: &TokenStream,
+    fields: &Fields,
+    values: impl IntoIterator<Item = TokenStream>,
+) -> TokenStream {
+    let values = values.into_iter();
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// An expression for an iterator over values built from the fields'
+/// exemplars, combining them fairly the same way tuples do.
+fn fields_exemplars(path: &TokenStream, fields: &Fields) -> syn::Result<TokenStream> {
+    if fields.is_empty() {
+        let value = construct(path, fields, []);
+        return Ok(quote!(::core::iter::once(#value)));
+    }
+
+    let exemplars = fields
+        .iter()
+        .map(FieldExemplars::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let count = exemplars.len();
+    let primaries = exemplars
+        .iter()
+        .enumerate()
+        .filter_map(|(dim, field)| field.bind_primary(dim));
+    let values = exemplars.iter().enumerate().map(|(dim, field)| {
+        let stream = field.stream(dim);
+        quote!(::exemplars::__private::component(#stream, __exemplars_index, #dim)?)
+    });
+    let value = construct(path, fields, values);
+
+    Ok(quote! {{
+        #(#primaries)*
+        ::exemplars::__private::product::<#count, _>(
+            move |__exemplars_index: &[usize; #count]| ::core::result::Result::Ok(#value),
+        )
+    }})
+}
+
+/// An expression for the number of values built by [`fields_exemplars`],
+/// which is the product of the field stream lengths.
+fn fields_cardinality(fields: &Fields) -> syn::Result<TokenStream> {
+    let cardinalities = fields
+        .iter()
+        .map(|field| FieldExemplars::parse(field).map(|field| field.cardinality()))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        ::exemplars::Cardinality::Finite(1)
+            #(.saturating_mul(#cardinalities))*
+    })
+}
+
+/// The exemplars of a single field, with overrides set with `#[exemplars(..)]`.
+struct FieldExemplars<'a> {
+    ty: &'a Type,
+    values: Option<ExprArray>,
+    with: Option<Path>,
+    primary: Option<Expr>,
+}
+
+impl<'a> FieldExemplars<'a> {
+    fn parse(field: &'a Field) -> syn::Result<Self> {
+        let mut result = Self {
+            ty: &field.ty,
+            values: None,
+            with: None,
+            primary: None,
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("exemplars"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("values") {
+                    let values: ExprArray = meta.value()?.parse()?;
+                    if values.elems.is_empty() {
+                        return Err(meta.error("`values` must not be empty"));
+                    }
+                    result.values = Some(values);
+                } else if meta.path.is_ident("with") {
+                    result.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("primary") {
+                    result.primary = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `values`, `with` or `primary`"));
+                }
+                Ok(())
+            })?;
+            if result.values.is_some() && result.with.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a field cannot have both `values` and `with`",
+                ));
+            }
+        }
+        Ok(result)
+    }
+
+    /// A statement evaluating the `primary` expression of field `dim` once,
+    /// for use by [`Self::stream`].
+    fn bind_primary(&self, dim: usize) -> Option<TokenStream> {
+        let ty = self.ty;
+        let primary = self.primary.as_ref()?;
+        let binding = primary_binding(dim);
+        Some(quote!(let #binding: #ty = #primary;))
+    }
+
+    /// An expression for the exemplars of field `dim`.
+    fn stream(&self, dim: usize) -> TokenStream {
+        let ty = self.ty;
+        let stream = if let Some(values) = &self.values {
+            let count = values.elems.len();
+            quote!({
+                let __exemplars_values: [#ty; #count] = #values;
+                __exemplars_values
+            })
+        } else if let Some(with) = &self.with {
+            quote!(#with())
+        } else {
+            quote!(<#ty as ::exemplars::Exemplars>::exemplars())
+        };
+        if self.primary.is_none() {
+            return stream;
+        }
+        let binding = primary_binding(dim);
+        quote! {
+            ::core::iter::Iterator::chain(
+                ::core::iter::once::<#ty>(::core::clone::Clone::clone(&#binding)),
+                ::core::iter::Iterator::filter(
+                    ::core::iter::IntoIterator::into_iter(#stream),
+                    |__exemplars_value: &#ty| ::core::cmp::PartialEq::ne(__exemplars_value, &#binding),
+                ),
+            )
+        }
+    }
+
+    /// An expression for the number of values in the field's stream.
+    fn cardinality(&self) -> TokenStream {
+        let ty = self.ty;
+        let cardinality = if let Some(values) = &self.values {
+            let count = values.elems.len();
+            quote!(::exemplars::Cardinality::Finite(#count))
+        } else if self.with.is_some() {
+            quote!(::exemplars::Cardinality::AtLeast(1))
+        } else {
+            quote!(<#ty as ::exemplars::Exemplars>::CARDINALITY)
+        };
+        // The primary may or may not be among the other values.
+        match &self.primary {
+            Some(_) => quote! {
+                match #cardinality {
+                    ::exemplars::Cardinality::Finite(__exemplars_count) => {
+                        ::exemplars::Cardinality::AtLeast(__exemplars_count)
+                    }
+                    __exemplars_cardinality => __exemplars_cardinality,
+                }
+            },
+            None => cardinality,
+        }
+    }
+}
+
+/// The variable holding the `primary` value of field `dim`.
+fn primary_binding(dim: usize) -> syn::Ident {
+    format_ident!("__exemplars_primary_{}", dim)
+}
@@ -0,0 +1,103 @@
+//! The number of exemplars a type provides.
+
+/// How many values a type's exemplar stream holds.
+///
+/// Counts that do not fit in a `usize` are reported as
+/// `AtLeast(usize::MAX)`, so `Finite` always holds the exact count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cardinality {
+    /// Exactly this many values.
+    Finite(usize),
+    /// At least this many values, possibly infinitely many.
+    AtLeast(usize),
+    /// Infinitely many values.
+    Unbounded,
+}
+
+use Cardinality::{AtLeast, Finite, Unbounded};
+
+impl Cardinality {
+    /// The cardinality of a stream with `count` values.
+    pub(crate) const fn of(count: u128) -> Self {
+        if count <= usize::MAX as u128 {
+            Finite(count as usize)
+        } else {
+            AtLeast(usize::MAX)
+        }
+    }
+
+    /// Returns the exact number of values, if known.
+    pub const fn finite(self) -> Option<usize> {
+        match self {
+            Finite(count) => Some(count),
+            AtLeast(_) | Unbounded => None,
+        }
+    }
+
+    /// Returns whether iterating over every value is guaranteed to end.
+    pub const fn is_finite(self) -> bool {
+        matches!(self, Finite(_))
+    }
+
+    /// The smallest number of values the stream may hold.
+    const fn lower(self) -> usize {
+        match self {
+            Finite(count) | AtLeast(count) => count,
+            Unbounded => usize::MAX,
+        }
+    }
+
+    /// The cardinality of two streams chained together.
+    pub const fn saturating_add(self, other: Self) -> Self {
+        match (self, other) {
+            (Unbounded, _) | (_, Unbounded) => Unbounded,
+            (Finite(a), Finite(b)) => match a.checked_add(b) {
+                Some(count) => Finite(count),
+                None => AtLeast(usize::MAX),
+            },
+            _ => AtLeast(self.lower().saturating_add(other.lower())),
+        }
+    }
+
+    /// The cardinality of the product of two streams.
+    pub const fn saturating_mul(self, other: Self) -> Self {
+        match (self, other) {
+            (Finite(0), _) | (_, Finite(0)) => Finite(0),
+            (Unbounded, _) | (_, Unbounded) => Unbounded,
+            (Finite(a), Finite(b)) => match a.checked_mul(b) {
+                Some(count) => Finite(count),
+                None => AtLeast(usize::MAX),
+            },
+            _ => AtLeast(self.lower().saturating_mul(other.lower())),
+        }
+    }
+
+    /// The cardinality of the product of `exp` copies of a stream.
+    pub const fn saturating_pow(self, mut exp: usize) -> Self {
+        let mut result = Finite(1);
+        let mut base = self;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result.saturating_mul(base);
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = base.saturating_mul(base);
+            }
+        }
+        result
+    }
+
+    /// The cardinality of the longer of two streams.
+    pub const fn max(self, other: Self) -> Self {
+        match (self, other) {
+            (Unbounded, _) | (_, Unbounded) => Unbounded,
+            (Finite(a), Finite(b)) => Finite(if a > b { a } else { b }),
+            _ => AtLeast(if self.lower() > other.lower() {
+                self.lower()
+            } else {
+                other.lower()
+            }),
+        }
+    }
+}
@@ -0,0 +1,391 @@
+//! Running assertions over the exemplars of a type, and checking
+//! `Exemplars` impls themselves.
+
+extern crate std;
+
+use core::{
+    any::Any,
+    fmt::{self, Debug, Write},
+    time::Duration,
+};
+use std::{
+    env, format,
+    panic::{self, AssertUnwindSafe},
+    string::String,
+    time::Instant,
+    vec::Vec,
+};
+
+use crate::{
+    shrink::{minimize, Shrink},
+    Cardinality, Exemplars,
+};
+
+/// The environment variable that overrides the number of exemplars to check.
+pub const LIMIT_VAR: &str = "EXEMPLARS_LIMIT";
+
+/// The number of exemplars [`check_impl`] looks at.
+pub const IMPL_LIMIT: usize = 100;
+
+/// The time [`check_impl`] allows for producing [`IMPL_LIMIT`] exemplars.
+pub const IMPL_TIME_LIMIT: Duration = Duration::from_secs(1);
+
+/// Run `test` on the first `limit` exemplars of `T`, and panic if it panics
+/// on any of them.
+///
+/// Every exemplar is checked, even after a failure. The final panic lists
+/// the position, the `Debug` output and the panic message of each failing
+/// exemplar.
+///
+/// The `EXEMPLARS_LIMIT` environment variable replaces `limit` when set, so
+/// that CI can check more exemplars than local runs do.
+///
+/// ```
+/// exemplars::check::for_all::<u8>(10, |x| assert_ne!(x, 0));
+/// ```
+#[track_caller]
+pub fn for_all<T: Exemplars + Debug>(limit: usize, mut test: impl FnMut(T)) {
+    let limit = limit_from_env(limit);
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (index, value) in T::exemplars().into_iter().take(limit).enumerate() {
+        checked += 1;
+        let debug = format!("{value:?}");
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| test(value))) {
+            failures.push((index, debug, String::from(panic_message(&*payload))));
+        }
+    }
+    report_failures(checked, failures);
+}
+
+/// Like [`for_all`], but shrinks each failing exemplar with
+/// [`minimize`](crate::shrink::minimize) before reporting it.
+///
+/// The report shows the simplest value that still fails next to the
+/// original exemplar, along with the panic message of the simplest one.
+///
+/// ```should_panic
+/// exemplars::check::for_all_shrinking::<u8>(10, |x| assert!(x < 5));
+/// ```
+#[track_caller]
+pub fn for_all_shrinking<T: Exemplars + Shrink + Clone + Debug>(
+    limit: usize,
+    mut test: impl FnMut(T),
+) {
+    let limit = limit_from_env(limit);
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    let mut fails = |value: &T| {
+        panic::catch_unwind(AssertUnwindSafe(|| test(value.clone())))
+            .err()
+            .map(|payload| String::from(panic_message(&*payload)))
+    };
+    for (index, value) in T::exemplars().into_iter().take(limit).enumerate() {
+        checked += 1;
+        let Some(mut message) = fails(&value) else {
+            continue;
+        };
+        let mut shrunk = false;
+        let simplest = minimize(value.clone(), |simpler| match fails(simpler) {
+            Some(simpler_message) => {
+                message = simpler_message;
+                shrunk = true;
+                true
+            }
+            None => false,
+        });
+        let debug = if shrunk {
+            format!("{value:?}, shrunk to {simplest:?}")
+        } else {
+            format!("{value:?}")
+        };
+        failures.push((index, debug, message));
+    }
+    report_failures(checked, failures);
+}
+
+/// Panic with a summary of `failures`, as positions, `Debug` output and panic
+/// messages, if there are any.
+#[track_caller]
+fn report_failures(checked: usize, failures: Vec<(usize, String, String)>) {
+    if failures.is_empty() {
+        return;
+    }
+    let mut report = format!("{} of {checked} exemplars failed:", failures.len());
+    for (index, debug, message) in failures {
+        // Writing to a `String` cannot fail.
+        let _ = write!(report, "\n  #{index}: {debug}\n    {message}");
+    }
+    panic!("{report}");
+}
+
+/// Returns the value of `EXEMPLARS_LIMIT` if it is set, and `limit`
+/// otherwise.
+#[track_caller]
+pub(crate) fn limit_from_env(limit: usize) -> usize {
+    let Ok(value) = env::var(LIMIT_VAR) else {
+        return limit;
+    };
+    match value.trim().parse() {
+        Ok(limit) => limit,
+        Err(_) => panic!("invalid {LIMIT_VAR}: {value:?} is not a number"),
+    }
+}
+
+/// The message of a panic payload, if it has one.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// The outcome of [`check_impl`] and [`check_impl_eq`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    type_name: &'static str,
+    checked: usize,
+    problems: Vec<String>,
+}
+
+impl Report {
+    /// Returns whether the impl passed every check.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// The problems found, one sentence each.
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+
+    /// Panic with the report if the impl failed any check.
+    #[track_caller]
+    pub fn assert_ok(&self) {
+        if !self.is_ok() {
+            panic!("{self}");
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            type_name,
+            checked,
+            problems,
+        } = self;
+        if problems.is_empty() {
+            return write!(
+                f,
+                "Exemplars for {type_name}: ok, {checked} exemplars checked"
+            );
+        }
+        let plural = if problems.len() == 1 { "" } else { "s" };
+        write!(
+            f,
+            "Exemplars for {type_name}: {} problem{plural} in {checked} exemplars checked:",
+            problems.len()
+        )?;
+        for problem in problems {
+            write!(f, "\n  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that the `Exemplars` impl of `T` keeps the parts of its contract
+/// that need no comparisons.
+///
+/// Looks at the first [`IMPL_LIMIT`] exemplars and checks that:
+///
+/// - there is at least one;
+/// - `exemplar()` does not panic;
+/// - they all arrive within [`IMPL_TIME_LIMIT`];
+/// - their number agrees with `CARDINALITY`.
+///
+/// Panics in the impl are caught and reported as problems. A single value
+/// that never arrives still hangs the check. Types with `PartialEq` get more
+/// checks from [`check_impl_eq`].
+///
+/// ```
+/// exemplars::check_impl::<std::sync::Mutex<bool>>().assert_ok();
+/// ```
+pub fn check_impl<T: Exemplars + Debug>() -> Report {
+    check_base::<T>().0
+}
+
+/// Check that the `Exemplars` impl of `T` keeps its contract.
+///
+/// Runs every check of [`check_impl`], and also checks that:
+///
+/// - `exemplar()` returns the first exemplar;
+/// - a second call to `exemplars()` returns the same values;
+/// - no two of them are equal.
+///
+/// ```
+/// exemplars::check_impl_eq::<Option<bool>>().assert_ok();
+/// ```
+pub fn check_impl_eq<T: Exemplars + PartialEq + Debug>() -> Report {
+    let (mut report, values, exemplar) = check_base::<T>();
+    let problems = &mut report.problems;
+    let Some(first) = values.first() else {
+        return report;
+    };
+
+    if let Some(exemplar) = exemplar.filter(|exemplar| exemplar != first) {
+        problems.push(format!(
+            "exemplar() returned {exemplar:?} instead of the first exemplar {first:?}"
+        ));
+    }
+
+    match collect::<T>(values.len()) {
+        Ok((again, _)) => {
+            if let Some((index, (a, b))) = values
+                .iter()
+                .zip(&again)
+                .enumerate()
+                .find(|(_, (a, b))| a != b)
+            {
+                problems.push(format!(
+                    "exemplar #{index} was {a:?} the first time and {b:?} the second time"
+                ));
+            } else if again.len() != values.len() {
+                problems.push(format!(
+                    "exemplars() returned {} values the first time and {} the second time",
+                    values.len(),
+                    again.len()
+                ));
+            }
+        }
+        Err(message) => problems.push(format!("exemplars() panicked the second time: {message}")),
+    }
+
+    for (index, value) in values.iter().enumerate() {
+        if let Some(earlier) = values[..index].iter().position(|other| other == value) {
+            problems.push(format!(
+                "exemplars #{earlier} and #{index} are both {value:?}"
+            ));
+        }
+    }
+
+    report
+}
+
+/// Runs the checks of [`check_impl`], also returning the exemplars it looked
+/// at and the value of `exemplar()` for further checks.
+fn check_base<T: Exemplars + Debug>() -> (Report, Vec<T>, Option<T>) {
+    let mut report = Report {
+        type_name: core::any::type_name::<T>(),
+        checked: 0,
+        problems: Vec::new(),
+    };
+    let problems = &mut report.problems;
+
+    let (values, timed_out) = match collect::<T>(IMPL_LIMIT) {
+        Ok(run) => run,
+        Err(message) => {
+            problems.push(format!("exemplars() panicked: {message}"));
+            return (report, Vec::new(), None);
+        }
+    };
+    report.checked = values.len();
+    if timed_out {
+        problems.push(format!(
+            "only {} exemplars arrived within {IMPL_TIME_LIMIT:?}",
+            values.len()
+        ));
+    }
+
+    if values.is_empty() {
+        problems.push(String::from("exemplars() returned no values"));
+        return (report, values, None);
+    }
+    let exemplar = match panic::catch_unwind(T::exemplar) {
+        Ok(exemplar) => Some(exemplar),
+        Err(payload) => {
+            problems.push(format!("exemplar() panicked: {}", panic_message(&*payload)));
+            None
+        }
+    };
+
+    if !timed_out {
+        let len = values.len();
+        let ended = len < IMPL_LIMIT;
+        let consistent = match T::CARDINALITY {
+            Cardinality::Finite(n) => n == len || !ended && n > len,
+            Cardinality::AtLeast(n) => n <= len || !ended,
+            Cardinality::Unbounded => !ended,
+        };
+        if !consistent {
+            let actual = if ended {
+                format!("{len}")
+            } else {
+                format!("at least {len}")
+            };
+            problems.push(format!(
+                "CARDINALITY is {:?} but exemplars() returned {actual} values",
+                T::CARDINALITY
+            ));
+        }
+    }
+
+    (report, values, exemplar)
+}
+
+/// Collects up to `limit` exemplars of `T`, stopping early once
+/// [`IMPL_TIME_LIMIT`] has passed. Also returns whether it stopped early.
+fn collect<T: Exemplars>(limit: usize) -> Result<(Vec<T>, bool), String> {
+    panic::catch_unwind(|| {
+        let start = Instant::now();
+        let mut values = Vec::new();
+        for value in T::exemplars().into_iter().take(limit) {
+            values.push(value);
+            if values.len() < limit && start.elapsed() > IMPL_TIME_LIMIT {
+                return (values, true);
+            }
+        }
+        (values, false)
+    })
+    .map_err(|payload| String::from(panic_message(&*payload)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_failure_is_reported() {
+        let mut seen = Vec::new();
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| {
+            for_all::<u8>(10, |x| {
+                seen.push(x);
+                assert!(x % 3 != 0, "{x} is a multiple of 3");
+            })
+        }))
+        .expect_err("3, 6 and 9 fail");
+        assert_eq!(seen, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let message = panic_message(&*payload);
+        assert_eq!(
+            message,
+            "3 of 10 exemplars failed:\n  #2: 3\n    3 is a multiple of 3\n  \
+             #5: 6\n    6 is a multiple of 3\n  #8: 9\n    9 is a multiple of 3"
+        );
+    }
+
+    #[test]
+    fn failures_are_shrunk() {
+        let payload = panic::catch_unwind(|| for_all_shrinking::<u8>(20, |x| assert!(x < 5)))
+            .expect_err("exemplars from 5 on fail");
+        let message = panic_message(&*payload);
+        assert!(
+            message.starts_with("16 of 20 exemplars failed:"),
+            "{message}"
+        );
+        assert!(message.contains("#19: 20, shrunk to 5\n"), "{message}");
+        assert!(message.contains("#4: 5\n"), "{message}");
+    }
+}
@@ -0,0 +1,245 @@
+//! Combinatorial test designs built from exemplars.
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use crate::{product::nth, Exemplars};
+
+/// A trait for values made of several parameters, each with its own
+/// exemplars.
+///
+/// Implemented for tuples of 2 to 12 `Exemplars` types.
+pub trait Pairwise: Sized {
+    /// Returns the number of exemplars of each parameter, up to `k`.
+    fn lens(k: usize) -> Vec<usize>;
+    /// Returns the value whose parameters are the exemplars at positions
+    /// `index`.
+    ///
+    /// `index` holds one position per parameter, each below the matching
+    /// length from `lens`.
+    fn at(index: &[usize]) -> Self;
+}
+
+/// Implement `Pairwise` for tuples of `Exemplars` types.
+macro_rules! impl_for_tuples {
+    ($(($($t:ident $i:tt),+);)+) => {$(
+        impl<$($t: Exemplars),+> Pairwise for ($($t,)+) {
+            fn lens(k: usize) -> Vec<usize> {
+                vec![$($t::exemplars().into_iter().take(k).count()),+]
+            }
+            fn at(index: &[usize]) -> Self {
+                ($(
+                    nth::<$t>(index[$i])
+                        .expect("exemplars must return the same values every time"),
+                )+)
+            }
+        }
+    )+}
+}
+impl_for_tuples! {
+    (A 0, B 1);
+    (A 0, B 1, C 2);
+    (A 0, B 1, C 2, D 3);
+    (A 0, B 1, C 2, D 3, E 4);
+    (A 0, B 1, C 2, D 3, E 4, F 5);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
+
+/// Iterate over values that cover every pair of parameter exemplars.
+///
+/// Each parameter of `T` is limited to its first `k` exemplars. For any two
+/// parameters, every combination of their exemplars shows up in at least one
+/// value. The number of values grows with the product of the two largest
+/// parameter counts rather than with the product of all of them.
+///
+/// The values come from a deterministic greedy construction, so they are the
+/// same on every run. The first value is built from the primary exemplars.
+///
+/// ```
+/// use exemplars::combinatorics::pairwise;
+///
+/// let values: Vec<(bool, bool, u8)> = pairwise(3).collect();
+/// assert_eq!(values[0], (true, true, 1));
+/// assert!(values.len() < 2 * 2 * 3);
+/// ```
+pub fn pairwise<T: Pairwise>(k: usize) -> impl Iterator<Item = T> {
+    covering_array(&T::lens(k))
+        .into_iter()
+        .map(|index| T::at(&index))
+}
+
+/// The pairs of parameter values not covered yet.
+struct Uncovered<'a> {
+    lens: &'a [usize],
+    /// One grid per pair of parameters `i < j`, indexed by `a * lens[j] + b`.
+    grids: Vec<Vec<bool>>,
+}
+
+impl<'a> Uncovered<'a> {
+    fn new(lens: &'a [usize]) -> Self {
+        let mut grids = Vec::new();
+        for i in 0..lens.len() {
+            for j in i + 1..lens.len() {
+                grids.push(vec![true; lens[i] * lens[j]]);
+            }
+        }
+        Self { lens, grids }
+    }
+
+    /// The grid and cell of value `a` of parameter `i` with value `b` of
+    /// parameter `j`.
+    fn cell(&self, (i, a): (usize, usize), (j, b): (usize, usize)) -> (usize, usize) {
+        let ((i, a), (j, b)) = if i < j {
+            ((i, a), (j, b))
+        } else {
+            ((j, b), (i, a))
+        };
+        let n = self.lens.len();
+        // Grids for all pairs starting with a smaller parameter come first.
+        let grid = i * (2 * n - i - 1) / 2 + (j - i - 1);
+        (grid, a * self.lens[j] + b)
+    }
+
+    fn contains(&self, first: (usize, usize), second: (usize, usize)) -> bool {
+        let (grid, cell) = self.cell(first, second);
+        self.grids[grid][cell]
+    }
+
+    fn remove(&mut self, first: (usize, usize), second: (usize, usize)) {
+        let (grid, cell) = self.cell(first, second);
+        self.grids[grid][cell] = false;
+    }
+
+    /// The first pair not covered yet, as parameter and value positions.
+    fn first(&self) -> Option<((usize, usize), (usize, usize))> {
+        let n = self.lens.len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .find_map(|(i, j)| {
+                let (grid, _) = self.cell((i, 0), (j, 0));
+                let cell = self.grids[grid].iter().position(|&uncovered| uncovered)?;
+                Some(((i, cell / self.lens[j]), (j, cell % self.lens[j])))
+            })
+    }
+}
+
+/// Rows of a covering array of strength 2 for parameters with the given
+/// numbers of values.
+///
+/// Each row starts from the first uncovered pair. The other parameters then
+/// take the value covering the most new pairs with the parameters set so far,
+/// preferring earlier values on ties.
+fn covering_array(lens: &[usize]) -> Vec<Vec<usize>> {
+    if lens.contains(&0) {
+        return Vec::new();
+    }
+    let mut uncovered = Uncovered::new(lens);
+    let mut rows = Vec::new();
+    while let Some(((i, a), (j, b))) = uncovered.first() {
+        let mut row = vec![None; lens.len()];
+        row[i] = Some(a);
+        row[j] = Some(b);
+        for param in 0..lens.len() {
+            if row[param].is_some() {
+                continue;
+            }
+            let gain = |value: usize| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(other, set)| Some((other, (*set)?)))
+                    .filter(|&(other, set)| uncovered.contains((param, value), (other, set)))
+                    .count()
+            };
+            // `max_by_key` keeps the last maximum, so search from the end.
+            let best = (0..lens[param]).rev().max_by_key(|&value| gain(value));
+            row[param] = best;
+        }
+        let row: Vec<usize> = row
+            .into_iter()
+            .map(|value| value.expect("every parameter has a value"))
+            .collect();
+        for first in 0..row.len() {
+            for second in first + 1..row.len() {
+                uncovered.remove((first, row[first]), (second, row[second]));
+            }
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that `rows` hold every pair of values of every two parameters.
+    fn assert_covers(lens: &[usize], rows: &[Vec<usize>]) {
+        assert!(rows.iter().all(|row| row.len() == lens.len()), "{lens:?}");
+        assert!(
+            rows.iter()
+                .all(|row| row.iter().zip(lens).all(|(value, len)| value < len)),
+            "{lens:?}"
+        );
+        for i in 0..lens.len() {
+            for j in i + 1..lens.len() {
+                for a in 0..lens[i] {
+                    for b in 0..lens[j] {
+                        assert!(
+                            rows.iter().any(|row| row[i] == a && row[j] == b),
+                            "{lens:?}: ({i}, {a}) with ({j}, {b}) is not covered"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn covers_every_pair() {
+        for lens in [
+            &[5, 2, 3, 5, 5][..],
+            &[2, 2],
+            &[3, 1, 4],
+            &[1, 1, 1],
+            &[4, 4, 4, 4, 4, 4],
+            &[2, 7, 1, 3],
+            &[6, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+        ] {
+            let rows = covering_array(lens);
+            assert_covers(lens, &rows);
+            let mut sorted = lens.to_vec();
+            sorted.sort_unstable();
+            let largest = sorted.iter().rev().take(2).product::<usize>();
+            assert!(rows.len() >= largest, "{lens:?}");
+            assert!(rows.len() <= lens.iter().product::<usize>(), "{lens:?}");
+        }
+    }
+
+    #[test]
+    fn starts_with_primary_exemplars() {
+        assert_eq!(covering_array(&[3, 2, 4])[0], [0, 0, 0]);
+    }
+
+    #[test]
+    fn empty_parameter_gives_no_rows() {
+        assert!(covering_array(&[3, 0, 2]).is_empty());
+        assert!(covering_array(&[0, 0]).is_empty());
+    }
+
+    #[test]
+    fn pairwise_covers_tuple_exemplars() {
+        let values: Vec<(bool, Option<bool>, u8)> = pairwise(4).collect();
+        assert_eq!(values.len(), 3 * 4);
+        for a in [true, false] {
+            for b in [Some(true), Some(false), None] {
+                assert!(values.iter().any(|v| (v.0, v.1) == (a, b)));
+            }
+        }
+    }
+}
@@ -1,7 +1,45 @@
 #![no_std]
 
+#[cfg(all(feature = "derive", feature = "std"))]
+pub use exemplars_derive::exemplar_test;
+#[cfg(feature = "derive")]
+pub use exemplars_derive::Exemplars;
+
+mod cardinality;
+#[cfg(feature = "std")]
+pub mod check;
+#[cfg(feature = "alloc")]
+pub mod combinatorics;
+mod product;
+mod sample;
+pub mod shrink;
+#[cfg(feature = "alloc")]
+pub mod strings;
+
+use core::{
+    cmp::Ordering,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    ops::{Bound, ControlFlow},
+    task::Poll,
+    time::Duration,
+};
+
+pub use cardinality::Cardinality;
+#[cfg(feature = "std")]
+pub use check::{check_impl, check_impl_eq};
+use product::{component, interleave, nth, unrank, Product};
+pub use sample::{sample, sample_by_walking};
+pub use shrink::Shrink;
+
 /// A trait for providing examples of a type.
 pub trait Exemplars: Sized {
+    /// The number of values returned from `exemplars()`.
+    ///
+    /// Defaults to `AtLeast(1)`, which is always correct. Finite streams
+    /// should report their exact length, so that callers know they can
+    /// exhaust them.
+    const CARDINALITY: Cardinality = Cardinality::AtLeast(1);
+
     /// Iterate over all available examples.
     ///
     /// Must return at least one value.
@@ -17,13 +55,160 @@ pub trait Exemplars: Sized {
     }
 }
 
+/// A trait for providing boundary values of a type.
+///
+/// `Exemplars` lists typical values; edge cases are the values most likely to
+/// break code instead: zero, extremes, empty collections and the like.
+pub trait EdgeCases: Sized {
+    /// Iterate over the edge cases of the type.
+    ///
+    /// May be empty if the type has no values worth singling out.
+    fn edge_cases() -> impl IntoIterator<Item = Self>;
+}
+
+/// A trait for providing examples of a type as static references.
+///
+/// Unlike `Exemplars`, this works for unsized types such as `str` and `[T]`.
+/// Every implementor also gets an `Exemplars` impl for `&'static Self`,
+/// without needing `alloc`.
+pub trait StaticExemplars: 'static {
+    /// Iterate over all available examples.
+    ///
+    /// Must return at least one value.
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self>;
+}
+
+/// A trait for looking up examples of a type by position.
+///
+/// Composite impls such as tuples and arrays compute the position of each
+/// component directly, so late examples are as cheap as early ones.
+pub trait IndexedExemplars: Exemplars {
+    /// Returns the example at position `index`, if there is one.
+    ///
+    /// Must return the same value as `exemplars().into_iter().nth(index)`.
+    fn exemplar_at(index: usize) -> Option<Self>;
+    /// Returns the number of examples, if known.
+    ///
+    /// Must return the exact number of examples when it is at most
+    /// `usize::MAX`. Only longer or infinite streams may return `None`.
+    /// Defaults to the count given by `Exemplars::CARDINALITY`.
+    fn len() -> Option<usize> {
+        Self::CARDINALITY.finite()
+    }
+    /// Returns the example at a position that may not fit in a `usize`.
+    ///
+    /// Only streams longer than `usize::MAX` need to override this, together
+    /// with `len_u128`. Defaults to `exemplar_at`.
+    fn exemplar_at_u128(index: u128) -> Option<Self> {
+        Self::exemplar_at(index.try_into().ok()?)
+    }
+    /// Returns the number of examples as a `u128`, if known.
+    ///
+    /// Defaults to `len`.
+    fn len_u128() -> Option<u128> {
+        Self::len().map(|len| len as u128)
+    }
+}
+
+impl<T: ?Sized + StaticExemplars> Exemplars for &'static T {
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        T::static_exemplars()
+    }
+}
+
 impl Exemplars for () {
+    const CARDINALITY: Cardinality = Cardinality::Finite(1);
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         [()]
     }
 }
 
-/// Implement `Exemplars` for number types with 1 as the primary example.
+impl StaticExemplars for () {
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+        [&()]
+    }
+}
+
+impl IndexedExemplars for () {
+    fn exemplar_at(index: usize) -> Option<Self> {
+        (index == 0).then_some(())
+    }
+}
+
+impl EdgeCases for () {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        core::iter::empty()
+    }
+}
+
+impl Exemplars for bool {
+    const CARDINALITY: Cardinality = Cardinality::Finite(2);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [true, false]
+    }
+}
+
+impl StaticExemplars for bool {
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+        [&true, &false]
+    }
+}
+
+impl IndexedExemplars for bool {
+    fn exemplar_at(index: usize) -> Option<Self> {
+        [true, false].get(index).copied()
+    }
+}
+
+impl EdgeCases for bool {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [false]
+    }
+}
+
+impl Exemplars for char {
+    const CARDINALITY: Cardinality = Cardinality::Finite(10);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        ['a', 'Z', '0', ' ', '\n', 'é', 'ß', 'Ж', '中', '😀']
+    }
+}
+
+impl IndexedExemplars for char {
+    fn exemplar_at(index: usize) -> Option<Self> {
+        Self::exemplars().into_iter().nth(index)
+    }
+}
+
+impl EdgeCases for char {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        ['\0', '\u{7f}', '\u{301}', '\u{200b}', '\u{fffd}', Self::MAX]
+    }
+}
+
+impl StaticExemplars for str {
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+        ["example", "example file", "ex\u{e4}mple", "a"]
+    }
+}
+
+/// Single-element slices of the static exemplars of `T`.
+impl<T: StaticExemplars> StaticExemplars for [T] {
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+        T::static_exemplars().into_iter().map(core::slice::from_ref)
+    }
+}
+
+impl StaticExemplars for core::ffi::CStr {
+    fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+        [c"example", c"example file", c"caf\xe9"]
+    }
+}
+
+/// Implement `Exemplars` for unsigned number types with 1 as the primary
+/// example.
 ///
 /// Generates impls like this one:
 ///
@@ -34,38 +219,755 @@ impl Exemplars for () {
 ///     }
 /// }
 /// ```
-macro_rules! impl_for_number_types {
+macro_rules! impl_for_unsigned_number_types {
     ($($t:ident),+) => {$(
         impl Exemplars for $t {
+            const CARDINALITY: Cardinality = Cardinality::of(Self::MAX as u128);
+
             fn exemplars() -> impl IntoIterator<Item = Self> {
                 1..=Self::MAX
             }
         }
+
+        impl IndexedExemplars for $t {
+            fn exemplar_at(index: usize) -> Option<Self> {
+                Self::exemplar_at_u128(index as u128)
+            }
+            fn exemplar_at_u128(index: u128) -> Option<Self> {
+                Self::try_from(index).ok()?.checked_add(1)
+            }
+            fn len_u128() -> Option<u128> {
+                Some(Self::MAX as u128)
+            }
+        }
+
+        /// The first few exemplars.
+        impl StaticExemplars for $t {
+            fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+                [&1, &2, &3]
+            }
+        }
+    )+}
+}
+impl_for_unsigned_number_types!(usize, u8, u16, u32, u64, u128);
+
+/// Implement `Exemplars` for signed number types with 1 as the primary
+/// example.
+///
+/// Values alternate in sign so that negative numbers show up right away:
+/// 1, -1, 2, -2, and so on up to `MAX` and `-MAX`, followed by `MIN`.
+///
+/// Generates impls like this one:
+///
+/// ```ignore
+/// impl Exemplars for i8 {
+///     fn exemplars() -> impl IntoIterator<Item = Self> {
+///         (0..u8::MAX).map(zigzag)
+///     }
+/// }
+/// ```
+///
+/// where `zigzag` maps a position in the stream to its value.
+macro_rules! impl_for_signed_number_types {
+    ($($t:ident($u:ident)),+) => {$(
+        const _: () = {
+            fn zigzag(i: $u) -> $t {
+                let magnitude = i / 2 + 1;
+                if i % 2 == 1 {
+                    -(magnitude as $t)
+                } else if magnitude > $t::MAX as $u {
+                    $t::MIN
+                } else {
+                    magnitude as $t
+                }
+            }
+
+            impl Exemplars for $t {
+                const CARDINALITY: Cardinality = Cardinality::of($u::MAX as u128);
+
+                fn exemplars() -> impl IntoIterator<Item = Self> {
+                    (0..$u::MAX).map(zigzag)
+                }
+            }
+
+            impl IndexedExemplars for $t {
+                fn exemplar_at(index: usize) -> Option<Self> {
+                    Self::exemplar_at_u128(index as u128)
+                }
+                fn exemplar_at_u128(index: u128) -> Option<Self> {
+                    let i = $u::try_from(index).ok().filter(|&i| i < $u::MAX)?;
+                    Some(zigzag(i))
+                }
+                fn len_u128() -> Option<u128> {
+                    Some($u::MAX as u128)
+                }
+            }
+
+            /// The first few exemplars.
+            impl StaticExemplars for $t {
+                fn static_exemplars() -> impl IntoIterator<Item = &'static Self> {
+                    [&1, &-1, &2, &-2]
+                }
+            }
+        };
+    )+}
+}
+impl_for_signed_number_types!(
+    isize(usize),
+    i8(u8),
+    i16(u16),
+    i32(u32),
+    i64(u64),
+    i128(u128)
+);
+
+/// Implement `EdgeCases` for unsigned integer types: zero and the maximum.
+macro_rules! impl_edge_cases_for_unsigned_types {
+    ($($t:ident),+) => {$(
+        impl EdgeCases for $t {
+            fn edge_cases() -> impl IntoIterator<Item = Self> {
+                [0, Self::MAX]
+            }
+        }
+    )+}
+}
+impl_edge_cases_for_unsigned_types!(usize, u8, u16, u32, u64, u128);
+
+/// Implement `EdgeCases` for signed integer types: zero, minus one and the
+/// extremes.
+macro_rules! impl_edge_cases_for_signed_types {
+    ($($t:ident),+) => {$(
+        impl EdgeCases for $t {
+            fn edge_cases() -> impl IntoIterator<Item = Self> {
+                [0, -1, Self::MIN, Self::MAX]
+            }
+        }
+    )+}
+}
+impl_edge_cases_for_signed_types!(isize, i8, i16, i32, i64, i128);
+
+/// Implement `Exemplars` for floating point types with 1.0 as the primary
+/// example.
+macro_rules! impl_for_float_types {
+    ($($t:ident),+) => {$(
+        impl Exemplars for $t {
+            const CARDINALITY: Cardinality = Cardinality::Finite(8);
+
+            fn exemplars() -> impl IntoIterator<Item = Self> {
+                [1.0, 0.5, -1.5, 2.0, 100.25, -0.001, 1.0e10, 1.0e-10]
+            }
+        }
+
+        impl IndexedExemplars for $t {
+            fn exemplar_at(index: usize) -> Option<Self> {
+                Self::exemplars().into_iter().nth(index)
+            }
+        }
+
+        impl EdgeCases for $t {
+            fn edge_cases() -> impl IntoIterator<Item = Self> {
+                [
+                    0.0,
+                    -0.0,
+                    Self::NAN,
+                    Self::INFINITY,
+                    Self::NEG_INFINITY,
+                    Self::MIN,
+                    Self::MAX,
+                    Self::MIN_POSITIVE,
+                    Self::MIN_POSITIVE / 2.0,
+                    Self::EPSILON,
+                ]
+            }
+        }
     )+}
 }
-impl_for_number_types!(usize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_for_float_types!(f32, f64);
+
+/// Implement `Exemplars`, `IndexedExemplars` and `EdgeCases` for non-zero
+/// integer types, reusing the values of the underlying integer type.
+macro_rules! impl_for_nonzero_types {
+    ($($t:ident($int:ident)),+) => {$(
+        impl Exemplars for core::num::$t {
+            // Integer exemplars never include zero.
+            const CARDINALITY: Cardinality = $int::CARDINALITY;
+
+            fn exemplars() -> impl IntoIterator<Item = Self> {
+                $int::exemplars().into_iter().filter_map(Self::new)
+            }
+        }
+
+        impl IndexedExemplars for core::num::$t {
+            fn exemplar_at(index: usize) -> Option<Self> {
+                $int::exemplar_at(index).and_then(Self::new)
+            }
+            fn exemplar_at_u128(index: u128) -> Option<Self> {
+                $int::exemplar_at_u128(index).and_then(Self::new)
+            }
+            fn len_u128() -> Option<u128> {
+                $int::len_u128()
+            }
+        }
+
+        impl EdgeCases for core::num::$t {
+            fn edge_cases() -> impl IntoIterator<Item = Self> {
+                $int::edge_cases().into_iter().filter_map(Self::new)
+            }
+        }
+    )+}
+}
+impl_for_nonzero_types!(
+    NonZeroUsize(usize),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroIsize(isize),
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128)
+);
 
 impl<T: Exemplars> Exemplars for Option<T> {
+    const CARDINALITY: Cardinality = T::CARDINALITY.saturating_add(Cardinality::Finite(1));
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         T::exemplars().into_iter().map(Some).chain([None])
     }
 }
 
+impl<T: IndexedExemplars> IndexedExemplars for Option<T> {
+    fn exemplar_at(index: usize) -> Option<Self> {
+        Self::exemplar_at_u128(index as u128)
+    }
+    fn len() -> Option<usize> {
+        T::len()?.checked_add(1)
+    }
+    fn exemplar_at_u128(index: u128) -> Option<Self> {
+        match T::exemplar_at_u128(index) {
+            Some(value) => Some(Some(value)),
+            None => (T::len_u128() == Some(index)).then_some(None),
+        }
+    }
+    fn len_u128() -> Option<u128> {
+        T::len_u128()?.checked_add(1)
+    }
+}
+
+impl<T: EdgeCases> EdgeCases for Option<T> {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [None]
+            .into_iter()
+            .chain(T::edge_cases().into_iter().map(Some))
+    }
+}
+
+/// `Ok` and `Err` values in turn, starting with `Ok(T::exemplar())`.
+impl<T: Exemplars, E: Exemplars> Exemplars for Result<T, E> {
+    const CARDINALITY: Cardinality = T::CARDINALITY.saturating_add(E::CARDINALITY);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        interleave(
+            T::exemplars().into_iter().map(Ok),
+            E::exemplars().into_iter().map(Err),
+        )
+    }
+}
+
+impl Exemplars for Ordering {
+    const CARDINALITY: Cardinality = Cardinality::Finite(3);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [Ordering::Less, Ordering::Equal, Ordering::Greater]
+    }
+}
+
+/// `Included` and `Excluded` values in turn, with `Unbounded` after the first
+/// pair.
+impl<T: Exemplars> Exemplars for Bound<T> {
+    const CARDINALITY: Cardinality = T::CARDINALITY
+        .saturating_add(T::CARDINALITY)
+        .saturating_add(Cardinality::Finite(1));
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        let mut bounds = interleave(
+            T::exemplars().into_iter().map(Bound::Included),
+            T::exemplars().into_iter().map(Bound::Excluded),
+        );
+        let first = [bounds.next(), bounds.next()];
+        first
+            .into_iter()
+            .flatten()
+            .chain([Bound::Unbounded])
+            .chain(bounds)
+    }
+}
+
+/// `Continue` and `Break` values in turn, starting with `Continue`.
+impl<B: Exemplars, C: Exemplars> Exemplars for ControlFlow<B, C> {
+    const CARDINALITY: Cardinality = C::CARDINALITY.saturating_add(B::CARDINALITY);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        interleave(
+            C::exemplars().into_iter().map(ControlFlow::Continue),
+            B::exemplars().into_iter().map(ControlFlow::Break),
+        )
+    }
+}
+
+/// `Ready(T::exemplar())`, then `Pending`, then the other `Ready` values.
+impl<T: Exemplars> Exemplars for Poll<T> {
+    const CARDINALITY: Cardinality = T::CARDINALITY.saturating_add(Cardinality::Finite(1));
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        interleave(T::exemplars().into_iter().map(Poll::Ready), [Poll::Pending])
+    }
+}
+
+/// Implement `Exemplars` for tuples, enumerating the product of the element
+/// streams fairly.
+///
+/// Generates impls like this one:
+///
+/// ```ignore
+/// impl<A: Exemplars, B: Exemplars> Exemplars for (A, B) {
+///     fn exemplars() -> impl IntoIterator<Item = Self> {
+///         let mut product = Product::<2>::new();
+///         core::iter::from_fn(move || {
+///             product.next_with(|index| {
+///                 Ok((
+///                     component::<A, 2>(index, 0)?,
+///                     component::<B, 2>(index, 1)?,
+///                 ))
+///             })
+///         })
+///     }
+///     fn exemplar() -> Self {
+///         (A::exemplar(), B::exemplar())
+///     }
+/// }
+/// ```
+macro_rules! impl_for_tuples {
+    ($($n:literal => ($($t:ident $i:tt),+);)+) => {$(
+        impl<$($t: Exemplars),+> Exemplars for ($($t,)+) {
+            const CARDINALITY: Cardinality =
+                Cardinality::Finite(1)$(.saturating_mul($t::CARDINALITY))+;
+
+            fn exemplars() -> impl IntoIterator<Item = Self> {
+                let mut product = Product::<$n>::new();
+                core::iter::from_fn(move || {
+                    product.next_with(|index| Ok(($(component::<$t, $n>(index, $i)?,)+)))
+                })
+            }
+            fn exemplar() -> Self {
+                ($($t::exemplar(),)+)
+            }
+        }
+
+        impl<$($t: IndexedExemplars),+> IndexedExemplars for ($($t,)+) {
+            fn exemplar_at(index: usize) -> Option<Self> {
+                Self::exemplar_at_u128(index as u128)
+            }
+            fn len() -> Option<usize> {
+                Some(1)$(.and_then(|len: usize| len.checked_mul($t::len()?)))+
+            }
+            fn exemplar_at_u128(index: u128) -> Option<Self> {
+                let index = unrank([$($t::len_u128()),+], index)?;
+                Some(($($t::exemplar_at_u128(index[$i])?,)+))
+            }
+            fn len_u128() -> Option<u128> {
+                Some(1)$(.and_then(|len: u128| len.checked_mul($t::len_u128()?)))+
+            }
+        }
+    )+}
+}
+impl_for_tuples! {
+    1 => (A 0);
+    2 => (A 0, B 1);
+    3 => (A 0, B 1, C 2);
+    4 => (A 0, B 1, C 2, D 3);
+    5 => (A 0, B 1, C 2, D 3, E 4);
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+    7 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    8 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    9 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    10 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    11 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    12 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
+
+impl<T: Exemplars, const N: usize> Exemplars for [T; N] {
+    const CARDINALITY: Cardinality = T::CARDINALITY.saturating_pow(N);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        let mut product = Product::<N>::new();
+        core::iter::from_fn(move || {
+            product.next_with(|index| {
+                // All positions share one stream, so the largest index decides.
+                if let Some((dim, &max)) = index.iter().enumerate().max_by_key(|&(_, i)| i) {
+                    if nth::<T>(max).is_none() {
+                        return Err(Some(dim));
+                    }
+                }
+                Ok(core::array::from_fn(|dim| {
+                    nth(index[dim]).expect("exemplars must return the same values every time")
+                }))
+            })
+        })
+    }
+    fn exemplar() -> Self {
+        core::array::from_fn(|_| T::exemplar())
+    }
+}
+
+impl<T: IndexedExemplars, const N: usize> IndexedExemplars for [T; N] {
+    fn exemplar_at(index: usize) -> Option<Self> {
+        Self::exemplar_at_u128(index as u128)
+    }
+    fn len() -> Option<usize> {
+        // There is exactly one empty array, even if `T` has no known length.
+        if N == 0 {
+            return Some(1);
+        }
+        T::len()?.checked_pow(N.try_into().ok()?)
+    }
+    fn exemplar_at_u128(index: u128) -> Option<Self> {
+        let index = unrank([T::len_u128(); N], index)?;
+        let values = index.map(T::exemplar_at_u128);
+        if values.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(values.map(|value| value.expect("checked above")))
+    }
+    fn len_u128() -> Option<u128> {
+        if N == 0 {
+            return Some(1);
+        }
+        T::len_u128()?.checked_pow(N.try_into().ok()?)
+    }
+}
+
+impl<T: Exemplars> Exemplars for core::cell::Cell<T> {
+    const CARDINALITY: Cardinality = T::CARDINALITY;
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        T::exemplars().into_iter().map(Self::new)
+    }
+}
+
+impl<T: Exemplars> Exemplars for core::cell::RefCell<T> {
+    const CARDINALITY: Cardinality = T::CARDINALITY;
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        T::exemplars().into_iter().map(Self::new)
+    }
+}
+
+/// Implement `Exemplars` for atomic types, reusing the exemplars of the
+/// underlying type.
+macro_rules! impl_for_atomic_types {
+    ($($width:literal => [$($t:ident($inner:ident)),+];)+) => {$($(
+        #[cfg(target_has_atomic = $width)]
+        impl Exemplars for core::sync::atomic::$t {
+            const CARDINALITY: Cardinality = $inner::CARDINALITY;
+
+            fn exemplars() -> impl IntoIterator<Item = Self> {
+                $inner::exemplars().into_iter().map(Self::new)
+            }
+        }
+    )+)+}
+}
+impl_for_atomic_types! {
+    "8" => [AtomicBool(bool), AtomicU8(u8), AtomicI8(i8)];
+    "16" => [AtomicU16(u16), AtomicI16(i16)];
+    "32" => [AtomicU32(u32), AtomicI32(i32)];
+    "64" => [AtomicU64(u64), AtomicI64(i64)];
+    "ptr" => [AtomicUsize(usize), AtomicIsize(isize)];
+}
+
+/// Documentation addresses from RFC 5737, then special-purpose ones.
+impl Exemplars for Ipv4Addr {
+    const CARDINALITY: Cardinality = Cardinality::Finite(9);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            Ipv4Addr::new(192, 0, 2, 1),
+            Ipv4Addr::new(198, 51, 100, 7),
+            Ipv4Addr::new(203, 0, 113, 254),
+            Ipv4Addr::LOCALHOST,
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::BROADCAST,
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(169, 254, 0, 1),
+            Ipv4Addr::new(224, 0, 0, 1),
+        ]
+    }
+}
+
+/// Documentation addresses from RFC 3849, then special-purpose ones.
+impl Exemplars for Ipv6Addr {
+    const CARDINALITY: Cardinality = Cardinality::Finite(7);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            Ipv6Addr::new(0x2001, 0xdb8, 0x85a3, 0, 0, 0x8a2e, 0x370, 0x7334),
+            Ipv6Addr::LOCALHOST,
+            Ipv6Addr::UNSPECIFIED,
+            Ipv4Addr::new(192, 0, 2, 1).to_ipv6_mapped(),
+            Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1),
+            Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1),
+        ]
+    }
+}
+
+impl Exemplars for IpAddr {
+    const CARDINALITY: Cardinality = Ipv4Addr::CARDINALITY.saturating_add(Ipv6Addr::CARDINALITY);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        interleave(
+            Ipv4Addr::exemplars().into_iter().map(IpAddr::V4),
+            Ipv6Addr::exemplars().into_iter().map(IpAddr::V6),
+        )
+    }
+}
+
+impl Exemplars for SocketAddrV4 {
+    const CARDINALITY: Cardinality = Cardinality::Finite(5);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            SocketAddrV4::new(Ipv4Addr::new(192, 0, 2, 1), 8080),
+            SocketAddrV4::new(Ipv4Addr::new(198, 51, 100, 7), 443),
+            SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80),
+            SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0),
+            SocketAddrV4::new(Ipv4Addr::BROADCAST, u16::MAX),
+        ]
+    }
+}
+
+/// Includes a link-local address with a scope id.
+impl Exemplars for SocketAddrV6 {
+    const CARDINALITY: Cardinality = Cardinality::Finite(5);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 8080, 0, 0),
+            SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0),
+            SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0),
+            SocketAddrV6::new(Ipv4Addr::new(192, 0, 2, 1).to_ipv6_mapped(), 80, 0, 0),
+            SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 8080, 0, 1),
+        ]
+    }
+}
+
+impl Exemplars for SocketAddr {
+    const CARDINALITY: Cardinality =
+        SocketAddrV4::CARDINALITY.saturating_add(SocketAddrV6::CARDINALITY);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        interleave(
+            SocketAddrV4::exemplars().into_iter().map(SocketAddr::V4),
+            SocketAddrV6::exemplars().into_iter().map(SocketAddr::V6),
+        )
+    }
+}
+
+/// One second, then sub-millisecond, minute- and day-scale durations and
+/// values next to `Duration::MAX`.
+impl Exemplars for Duration {
+    const CARDINALITY: Cardinality = Cardinality::Finite(9);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            Duration::from_secs(1),
+            Duration::from_micros(250),
+            Duration::from_nanos(1),
+            Duration::from_millis(1500),
+            Duration::from_secs(90),
+            Duration::from_secs(60 * 60),
+            Duration::from_secs(3 * 24 * 60 * 60),
+            Duration::MAX - Duration::from_nanos(1),
+            Duration::MAX,
+        ]
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod alloc {
     extern crate alloc;
 
-    use crate::Exemplars;
+    use alloc::{
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+        ffi::CString,
+        rc::Rc,
+        string::String,
+        vec::Vec,
+    };
+    use core::ffi::CStr;
+
+    use crate::{
+        product::{map_cardinality, maps, sequences, set_cardinality, sets},
+        Cardinality, EdgeCases, Exemplars,
+    };
+
+    /// `"example"`, followed by strings of character exemplars of growing
+    /// length.
+    impl Exemplars for String {
+        const CARDINALITY: Cardinality = Cardinality::Unbounded;
 
-    impl Exemplars for alloc::string::String {
         fn exemplars() -> impl IntoIterator<Item = Self> {
             ["example".into()]
+                .into_iter()
+                .chain(sequences::<char>().map(Self::from_iter))
+        }
+    }
+
+    impl EdgeCases for String {
+        fn edge_cases() -> impl IntoIterator<Item = Self> {
+            [Self::new()]
+        }
+    }
+
+    /// Vectors of growing length, starting with the primary exemplar of `T`
+    /// on its own.
+    impl<T: Exemplars> Exemplars for Vec<T> {
+        const CARDINALITY: Cardinality = Cardinality::Unbounded;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sequences::<T>().map(Self::from_iter)
+        }
+    }
+
+    impl<T: EdgeCases> EdgeCases for Vec<T> {
+        fn edge_cases() -> impl IntoIterator<Item = Self> {
+            [Self::new()]
+                .into_iter()
+                .chain(T::edge_cases().into_iter().map(|x| alloc::vec![x]))
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for VecDeque<T> {
+        const CARDINALITY: Cardinality = Cardinality::Unbounded;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sequences::<T>().map(Self::from_iter)
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for LinkedList<T> {
+        const CARDINALITY: Cardinality = Cardinality::Unbounded;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sequences::<T>().map(Self::from_iter)
+        }
+    }
+
+    impl<T: Exemplars + Ord> Exemplars for BinaryHeap<T> {
+        const CARDINALITY: Cardinality = Cardinality::Unbounded;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sequences::<T>().map(Self::from_iter)
+        }
+    }
+
+    impl<T: Exemplars + Ord> Exemplars for BTreeSet<T> {
+        const CARDINALITY: Cardinality = set_cardinality(T::CARDINALITY);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sets(Self::len)
+        }
+    }
+
+    impl<K: Exemplars + Ord, V: Exemplars> Exemplars for BTreeMap<K, V> {
+        const CARDINALITY: Cardinality = map_cardinality(K::CARDINALITY, V::CARDINALITY);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            maps(Self::len)
+        }
+    }
+
+    impl Exemplars for CString {
+        const CARDINALITY: Cardinality = Cardinality::Finite(5);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            [
+                c"example",
+                c"example file",
+                c"ex\xc3\xa4mple",
+                c"caf\xe9",
+                c"line one\nline two",
+            ]
+            .map(Self::from)
+        }
+    }
+
+    impl Exemplars for Box<CStr> {
+        const CARDINALITY: Cardinality = CString::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            CString::exemplars()
+                .into_iter()
+                .map(CString::into_boxed_c_str)
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for Box<T> {
+        const CARDINALITY: Cardinality = T::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            T::exemplars().into_iter().map(Self::new)
+        }
+    }
+
+    impl Exemplars for Box<str> {
+        const CARDINALITY: Cardinality = String::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            String::exemplars().into_iter().map(String::into_boxed_str)
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for Box<[T]> {
+        const CARDINALITY: Cardinality = Vec::<T>::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            Vec::exemplars().into_iter().map(Vec::into_boxed_slice)
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for Rc<T> {
+        const CARDINALITY: Cardinality = T::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            T::exemplars().into_iter().map(Self::new)
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl<T: Exemplars> Exemplars for alloc::sync::Arc<T> {
+        const CARDINALITY: Cardinality = T::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            T::exemplars().into_iter().map(Self::new)
         }
     }
 
-    impl<T: Exemplars> Exemplars for alloc::vec::Vec<T> {
+    /// Owned values built from the exemplars of the owned type.
+    impl<B> Exemplars for Cow<'_, B>
+    where
+        B: ToOwned + ?Sized,
+        B::Owned: Exemplars,
+    {
+        const CARDINALITY: Cardinality = B::Owned::CARDINALITY;
+
         fn exemplars() -> impl IntoIterator<Item = Self> {
-            T::exemplars().into_iter().map(|x| alloc::vec![x])
+            B::Owned::exemplars().into_iter().map(Cow::Owned)
         }
     }
 }
@@ -73,32 +975,259 @@ mod alloc {
 #[cfg(feature = "std")]
 mod std {
     extern crate std;
+
+    use core::hash::{BuildHasher, Hash};
+    use core::time::Duration;
+    use std::{
+        collections::{HashMap, HashSet},
+        ffi::OsString,
+        path::PathBuf,
+        sync::{Mutex, RwLock},
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use crate::{
+        product::{map_cardinality, maps, set_cardinality, sets},
+        Cardinality, Exemplars,
+    };
+
+    impl<T, S> Exemplars for HashSet<T, S>
+    where
+        T: Exemplars + Eq + Hash,
+        S: BuildHasher + Default,
+    {
+        const CARDINALITY: Cardinality = set_cardinality(T::CARDINALITY);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            sets(Self::len)
+        }
+    }
+
+    impl<K, V, S> Exemplars for HashMap<K, V, S>
+    where
+        K: Exemplars + Eq + Hash,
+        V: Exemplars,
+        S: BuildHasher + Default,
+    {
+        const CARDINALITY: Cardinality = map_cardinality(K::CARDINALITY, V::CARDINALITY);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            maps(Self::len)
+        }
+    }
+
+    /// Whether `non_utf8` returns a value on this platform.
+    const HAS_NON_UTF8: bool = cfg!(any(unix, windows));
+
+    /// A string that is not valid UTF-8, where the platform allows one.
+    fn non_utf8() -> Option<OsString> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+            Some(OsString::from_vec(b"caf\xe9.txt".to_vec()))
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            // An unpaired surrogate.
+            Some(OsString::from_wide(&[0x63, 0x61, 0x66, 0xd800]))
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            None
+        }
+    }
+
+    impl Exemplars for OsString {
+        const CARDINALITY: Cardinality = Cardinality::Finite(4 + HAS_NON_UTF8 as usize);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            ["example", "example file", "ex\u{e4}mple", ".example"]
+                .map(Self::from)
+                .into_iter()
+                .chain(non_utf8())
+        }
+    }
+
+    /// Relative, absolute, dotted and spaced paths, then a non-UTF-8 one.
+    impl Exemplars for PathBuf {
+        const CARDINALITY: Cardinality = Cardinality::Finite(9 + HAS_NON_UTF8 as usize);
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            [
+                "example.txt",
+                "dir/example.txt",
+                "/tmp/example.txt",
+                "./example.txt",
+                "../example.txt",
+                ".example",
+                "example.tar.gz",
+                "example file.txt",
+                "dir/",
+            ]
+            .map(Self::from)
+            .into_iter()
+            .chain(non_utf8().map(Self::from))
+        }
+    }
+
+    /// Fixed timestamps, starting with 2023-11-14T22:13:20Z. The clock is
+    /// never read.
+    impl Exemplars for SystemTime {
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            [
+                UNIX_EPOCH.checked_add(Duration::from_secs(1_700_000_000)),
+                Some(UNIX_EPOCH),
+                UNIX_EPOCH.checked_add(Duration::from_secs(1_000_000_000)),
+                UNIX_EPOCH.checked_add(Duration::from_millis(951_782_400_123)),
+                UNIX_EPOCH.checked_add(Duration::from_secs(1 << 31)),
+                UNIX_EPOCH.checked_add(Duration::from_secs(253_402_300_799)),
+                UNIX_EPOCH.checked_sub(Duration::from_secs(1)),
+            ]
+            .into_iter()
+            .flatten()
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for Mutex<T> {
+        const CARDINALITY: Cardinality = T::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            T::exemplars().into_iter().map(Self::new)
+        }
+    }
+
+    impl<T: Exemplars> Exemplars for RwLock<T> {
+        const CARDINALITY: Cardinality = T::CARDINALITY;
+
+        fn exemplars() -> impl IntoIterator<Item = Self> {
+            T::exemplars().into_iter().map(Self::new)
+        }
+    }
+}
+
+/// Support code for the derive and attribute macros. Not part of the public
+/// API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    extern crate std;
+
+    #[cfg(feature = "std")]
+    use core::fmt::Debug;
+    #[cfg(feature = "std")]
+    use std::{
+        format,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use crate::product::Product;
+    #[cfg(feature = "std")]
+    use crate::{
+        check::{limit_from_env, panic_message},
+        Exemplars,
+    };
+
+    #[cfg(feature = "std")]
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// Runs `test` on the first `limit` exemplars of `T`, failing with the
+    /// position and value of the first exemplar it panics on.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn run<T: Exemplars + Debug>(limit: usize, mut test: impl FnMut(T)) {
+        let limit = limit_from_env(limit);
+        for (index, value) in T::exemplars().into_iter().take(limit).enumerate() {
+            let debug = format!("{value:?}");
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| test(value))) {
+                panic!(
+                    "test failed on exemplar #{index}: {debug}\n{}",
+                    panic_message(&*payload)
+                );
+            }
+        }
+    }
+
+    /// Walks the product of `N` streams fairly, building each value with
+    /// `get` as in [`Product::next_with`].
+    pub fn product<const N: usize, T>(
+        mut get: impl FnMut(&[usize; N]) -> Result<T, Option<usize>>,
+    ) -> impl Iterator<Item = T> {
+        let mut product = Product::<N>::new();
+        core::iter::from_fn(move || product.next_with(&mut get))
+    }
+
+    /// Returns the value of `stream` for dimension `dim` of an index vector,
+    /// for use in [`product`].
+    pub fn component<I: IntoIterator, const N: usize>(
+        stream: I,
+        index: &[usize; N],
+        dim: usize,
+    ) -> Result<I::Item, Option<usize>> {
+        stream.into_iter().nth(index[dim]).ok_or(Some(dim))
+    }
 }
 
 #[cfg(feature = "bigdecimal_03")]
 impl Exemplars for ::bigdecimal_03::BigDecimal {
+    const CARDINALITY: Cardinality = Cardinality::Finite(1);
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         [<Self as ::bigdecimal_03::One>::one()]
     }
 }
 
+#[cfg(feature = "bigdecimal_03")]
+impl EdgeCases for ::bigdecimal_03::BigDecimal {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [<Self as ::bigdecimal_03::Zero>::zero()]
+    }
+}
+
 #[cfg(feature = "bigdecimal_04")]
 impl Exemplars for ::bigdecimal_04::BigDecimal {
+    const CARDINALITY: Cardinality = Cardinality::Finite(1);
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         [<Self as ::bigdecimal_04::One>::one()]
     }
 }
 
+#[cfg(feature = "bigdecimal_04")]
+impl EdgeCases for ::bigdecimal_04::BigDecimal {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [<Self as ::bigdecimal_04::Zero>::zero()]
+    }
+}
+
 #[cfg(feature = "rust_decimal")]
 impl Exemplars for ::rust_decimal::Decimal {
+    const CARDINALITY: Cardinality = Cardinality::Finite(1);
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         [Self::ONE]
     }
 }
 
+#[cfg(feature = "rust_decimal")]
+impl EdgeCases for ::rust_decimal::Decimal {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [Self::ZERO, Self::NEGATIVE_ONE, Self::MIN, Self::MAX]
+    }
+}
+
 #[cfg(feature = "uuid")]
 impl Exemplars for ::uuid::Uuid {
+    const CARDINALITY: Cardinality = Cardinality::Finite(1);
+
     fn exemplars() -> impl IntoIterator<Item = Self> {
         [Self::max()]
     }
 }
+
+#[cfg(feature = "uuid")]
+impl EdgeCases for ::uuid::Uuid {
+    fn edge_cases() -> impl IntoIterator<Item = Self> {
+        [Self::nil()]
+    }
+}
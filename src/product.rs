@@ -0,0 +1,471 @@
+//! Fair enumeration of products and sums of exemplar streams.
+
+#[cfg(feature = "alloc")]
+use crate::Cardinality;
+use crate::Exemplars;
+
+/// Returns the exemplar of `T` at position `index`, if there is one.
+pub(crate) fn nth<T: Exemplars>(index: usize) -> Option<T> {
+    T::exemplars().into_iter().nth(index)
+}
+
+/// Returns the exemplar of `T` for dimension `dim` of an index vector, for use
+/// in [`Product::next_with`].
+pub(crate) fn component<T: Exemplars, const N: usize>(
+    index: &[usize; N],
+    dim: usize,
+) -> Result<T, Option<usize>> {
+    nth(index[dim]).ok_or(Some(dim))
+}
+
+/// Alternates between two streams, continuing with the other one once either
+/// runs out.
+pub(crate) fn interleave<T>(
+    a: impl IntoIterator<Item = T>,
+    b: impl IntoIterator<Item = T>,
+) -> impl Iterator<Item = T> {
+    let mut a = a.into_iter().fuse();
+    let mut b = b.into_iter().fuse();
+    let mut turn_b = false;
+    core::iter::from_fn(move || {
+        let item = if turn_b {
+            b.next().or_else(|| a.next())
+        } else {
+            a.next().or_else(|| b.next())
+        };
+        turn_b = !turn_b;
+        item
+    })
+}
+
+/// Walks the index vectors of an `N`-dimensional product fairly.
+///
+/// Vectors are visited in shells: shell `k` holds every vector whose largest
+/// index is `k`, in lexicographic order. This is the `N`-dimensional analogue
+/// of Cantor's diagonal: every combination is reached at a finite position
+/// even when some of the streams are infinite, and the first vector is the
+/// all-zeros one, i.e. the combination of primary exemplars.
+///
+/// Stream lengths are not known up front. They are learned from the lookup
+/// function as streams run out, and the walk stops at the first shell that
+/// has no valid vectors left.
+pub(crate) struct Product<const N: usize> {
+    /// Exclusive upper bounds on each index, as far as they are known.
+    bounds: [usize; N],
+    /// The shell being walked.
+    shell: usize,
+    /// The next candidate in the current shell.
+    next: Option<[usize; N]>,
+    /// Whether the current shell has produced a value yet.
+    found: bool,
+}
+
+impl<const N: usize> Product<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            bounds: [usize::MAX; N],
+            shell: 0,
+            next: Some([0; N]),
+            found: false,
+        }
+    }
+
+    /// Returns the value at the next valid index vector.
+    ///
+    /// `get` builds the value for an index vector. Otherwise it returns the
+    /// dimension whose stream has no value at that index, or `None` to skip
+    /// the vector without learning anything about the streams.
+    pub(crate) fn next_with<T>(
+        &mut self,
+        mut get: impl FnMut(&[usize; N]) -> Result<T, Option<usize>>,
+    ) -> Option<T> {
+        loop {
+            let Some(candidate) = self.next else {
+                if !self.found {
+                    return None;
+                }
+                self.shell += 1;
+                self.found = false;
+                self.next = self.first();
+                continue;
+            };
+            self.next = self.successor(candidate);
+            if candidate
+                .iter()
+                .zip(&self.bounds)
+                .any(|(i, bound)| i >= bound)
+            {
+                continue;
+            }
+            match get(&candidate) {
+                Ok(value) => {
+                    self.found = true;
+                    return Some(value);
+                }
+                Err(Some(dim)) => self.bounds[dim] = self.bounds[dim].min(candidate[dim]),
+                Err(None) => {}
+            }
+        }
+    }
+
+    /// The largest index dimension `dim` can take in the current shell.
+    fn limit(&self, dim: usize) -> usize {
+        self.bounds[dim].saturating_sub(1).min(self.shell)
+    }
+
+    /// The first candidate of the current shell.
+    fn first(&self) -> Option<[usize; N]> {
+        let mut vector = [0; N];
+        let last = (0..N).rev().find(|&dim| self.bounds[dim] > self.shell)?;
+        vector[last] = self.shell;
+        Some(vector)
+    }
+
+    /// The candidate following `vector` in the current shell.
+    fn successor(&self, mut vector: [usize; N]) -> Option<[usize; N]> {
+        let shell = self.shell;
+        let mut end = N;
+        loop {
+            let dim = (0..end).rev().find(|&dim| vector[dim] < self.limit(dim))?;
+            vector[dim] += 1;
+            vector[dim + 1..].fill(0);
+            if vector[..=dim].contains(&shell) {
+                return Some(vector);
+            }
+            // The smallest completion puts the shell index as far right as possible.
+            if let Some(last) = (dim + 1..N).rev().find(|&last| self.bounds[last] > shell) {
+                vector[last] = shell;
+                return Some(vector);
+            }
+            end = dim + 1;
+        }
+    }
+}
+
+/// Returns the index vector at position `index` of the walk done by
+/// [`Product`], given the length of each stream.
+///
+/// A length of `None` stands for a stream too long for its length to matter,
+/// so `lens` must hold the exact length of every shorter stream. Positions
+/// from `u128::MAX` on are treated as past the end.
+pub(crate) fn unrank<const N: usize>(lens: [Option<u128>; N], index: u128) -> Option<[u128; N]> {
+    // Unknown lengths are at least as large as any index that is not past the
+    // end, so `u128::MAX` will do.
+    let lens = lens.map(|len| len.unwrap_or(u128::MAX));
+    // The number of vectors whose indices are all below `bound`. Saturating
+    // is fine, as counts past `u128::MAX` are only ever compared to an index.
+    let count = |lens: &[u128], bound: u128| {
+        lens.iter()
+            .fold(1u128, |count, &len| count.saturating_mul(len.min(bound)))
+    };
+    let before = |shell: u128| count(&lens, shell);
+    if N == 0 || lens.contains(&0) {
+        return (N == 0 && index == 0).then_some([0; N]);
+    }
+    if before(u128::MAX) <= index {
+        return None;
+    }
+
+    // Shell `k` is the last one with fewer than `index + 1` vectors before it.
+    // Every shell up to it holds a vector, so it is at most `index`.
+    let (mut low, mut high) = (0, index);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if before(mid) <= index {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    let shell = low;
+    let mut rank = index - before(shell);
+
+    // Walk the shell in lexicographic order, one dimension at a time.
+    let mut vector = [0; N];
+    let mut reached = false;
+    for dim in 0..N {
+        let rest = &lens[dim + 1..];
+        // Completions of the remaining dimensions within the shell, and those
+        // staying strictly below the shell index.
+        let within = count(rest, shell.saturating_add(1));
+        let below = count(rest, shell);
+        let value = if reached {
+            let value = rank / within;
+            rank %= within;
+            value
+        } else {
+            // Values below the shell index still need a completion that
+            // reaches it.
+            let completions = within - below;
+            let smaller = lens[dim].min(shell).saturating_mul(completions);
+            if rank < smaller {
+                let value = rank / completions;
+                rank %= completions;
+                value
+            } else {
+                rank -= smaller;
+                reached = true;
+                shell
+            }
+        };
+        vector[dim] = value;
+    }
+    Some(vector)
+}
+
+/// Repeats the exemplars of `T` forever.
+#[cfg(feature = "alloc")]
+fn cycle<T: Exemplars>() -> impl Iterator<Item = T> {
+    core::iter::repeat_with(T::exemplars).flatten()
+}
+
+/// Iterate over element sequences of growing length.
+///
+/// Lengths and starting offsets into the element stream are enumerated
+/// fairly, beginning with a single primary element. The element stream is
+/// restarted as often as needed, so sequences of any length can be filled.
+#[cfg(feature = "alloc")]
+pub(crate) fn sequences<T: Exemplars>() -> impl Iterator<Item = impl Iterator<Item = T>> {
+    let mut product = Product::<2>::new();
+    core::iter::from_fn(move || {
+        product.next_with(|&[length, offset]| {
+            if nth::<T>(offset).is_none() {
+                return Err(Some(1));
+            }
+            Ok(cycle::<T>().skip(offset).take(length + 1))
+        })
+    })
+}
+
+/// Iterate over sets of growing size, like [`sequences`].
+///
+/// A set whose elements collide comes out smaller than intended, so it is
+/// skipped, and sets of that size or larger are not attempted again. A set
+/// holding every exemplar is the same whatever the offset, so it is only
+/// produced once.
+#[cfg(feature = "alloc")]
+pub(crate) fn sets<T: Exemplars, C: FromIterator<T>>(
+    len: fn(&C) -> usize,
+) -> impl Iterator<Item = C> {
+    let mut product = Product::<2>::new();
+    core::iter::from_fn(move || {
+        product.next_with(|&[length, offset]| {
+            if nth::<T>(offset).is_none() {
+                return Err(Some(1));
+            }
+            let set: C = cycle::<T>().skip(offset).take(length + 1).collect();
+            if len(&set) <= length {
+                return Err(Some(0));
+            }
+            if offset > 0 && nth::<T>(length + 1).is_none() {
+                return Err(None);
+            }
+            Ok(set)
+        })
+    })
+}
+
+/// The number of sets produced by [`sets`] from a stream of elements.
+///
+/// A stream of `n` elements yields `n` sets of every size below `n`, one per
+/// offset, and a single set holding every element.
+#[cfg(feature = "alloc")]
+pub(crate) const fn set_cardinality(elements: Cardinality) -> Cardinality {
+    match elements {
+        Cardinality::Finite(n) => Cardinality::of(n as u128 * n.saturating_sub(1) as u128 + 1),
+        other => other,
+    }
+}
+
+/// The number of maps produced by [`maps`] from streams of keys and values.
+#[cfg(feature = "alloc")]
+pub(crate) const fn map_cardinality(keys: Cardinality, values: Cardinality) -> Cardinality {
+    set_cardinality(keys).saturating_mul(values)
+}
+
+/// Iterate over maps of growing size, like [`sets`].
+///
+/// Keys and values are drawn from separate offsets into their streams, which
+/// are enumerated fairly together with the size. Maps with colliding keys are
+/// skipped the same way.
+#[cfg(feature = "alloc")]
+pub(crate) fn maps<K: Exemplars, V: Exemplars, C: FromIterator<(K, V)>>(
+    len: fn(&C) -> usize,
+) -> impl Iterator<Item = C> {
+    let mut product = Product::<3>::new();
+    core::iter::from_fn(move || {
+        product.next_with(|&[length, key_offset, value_offset]| {
+            if nth::<K>(key_offset).is_none() {
+                return Err(Some(1));
+            }
+            if nth::<V>(value_offset).is_none() {
+                return Err(Some(2));
+            }
+            let keys = cycle::<K>().skip(key_offset);
+            let values = cycle::<V>().skip(value_offset);
+            let map: C = keys.zip(values).take(length + 1).collect();
+            if len(&map) <= length {
+                return Err(Some(0));
+            }
+            if key_offset > 0 && nth::<K>(length + 1).is_none() {
+                return Err(None);
+            }
+            Ok(map)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{collections::BTreeSet, vec::Vec};
+    use core::cmp::Ordering;
+
+    use super::*;
+    use crate::IndexedExemplars;
+
+    /// Walks the product of streams with the given lengths, `None` meaning
+    /// infinite, returning at most `limit` index vectors.
+    fn walk<const N: usize>(lens: [Option<usize>; N], limit: usize) -> Vec<[usize; N]> {
+        let mut product = Product::<N>::new();
+        core::iter::from_fn(|| {
+            product.next_with(|index| {
+                match (0..N).find(|&dim| lens[dim].is_some_and(|len| index[dim] >= len)) {
+                    Some(dim) => Err(Some(dim)),
+                    None => Ok(*index),
+                }
+            })
+        })
+        .take(limit)
+        .collect()
+    }
+
+    fn exemplars<T: Exemplars>() -> Vec<T> {
+        T::exemplars().into_iter().collect()
+    }
+
+    #[test]
+    fn walk_visits_every_vector_once_in_shells() {
+        for lens in [[1, 1, 1], [2, 2, 2], [3, 1, 4], [5, 2, 1], [1, 6, 2]] {
+            let vectors = walk(lens.map(Some), usize::MAX);
+            assert_eq!(vectors.len(), lens.iter().product::<usize>(), "{lens:?}");
+            let distinct: BTreeSet<_> = vectors.iter().collect();
+            assert_eq!(distinct.len(), vectors.len(), "{lens:?}");
+            let shells: Vec<usize> = vectors
+                .iter()
+                .map(|v| v.iter().copied().max().unwrap())
+                .collect();
+            assert!(shells.windows(2).all(|w| w[0] <= w[1]), "{lens:?}");
+            assert_eq!(vectors[0], [0; 3]);
+        }
+    }
+
+    #[test]
+    fn walk_of_empty_stream_is_empty() {
+        assert!(walk([Some(3), Some(0), None], 100).is_empty());
+        assert_eq!(walk::<0>([], 100), [[]]);
+    }
+
+    #[test]
+    fn walk_reaches_late_indices_of_infinite_streams() {
+        let vectors = walk([None, Some(2)], 1000);
+        assert_eq!(vectors.len(), 1000);
+        assert!(vectors.contains(&[400, 1]));
+        assert!(vectors.iter().all(|&[_, b]| b < 2));
+    }
+
+    #[test]
+    fn bool_triples_are_all_distinct() {
+        let values = exemplars::<(bool, bool, bool)>();
+        assert_eq!(values.len(), 8);
+        let distinct: BTreeSet<_> = values.iter().collect();
+        assert_eq!(distinct.len(), 8);
+        assert_eq!(values[0], (true, true, true));
+    }
+
+    #[test]
+    fn infinite_products_reach_other_streams_early() {
+        let values: Vec<(u64, bool)> = <(u64, bool)>::exemplars().into_iter().take(4).collect();
+        assert_eq!(values[0], (1, true));
+        assert!(values.contains(&(1, false)));
+    }
+
+    #[test]
+    fn finite_products_end_with_exact_count() {
+        assert_eq!(exemplars::<((), ())>().len(), 1);
+        assert_eq!(exemplars::<(bool, u8)>().len(), 2 * 255);
+        assert_eq!(exemplars::<(Option<bool>, bool, ())>().len(), 3 * 2);
+        assert_eq!(exemplars::<(bool, [bool; 2], Ordering)>().len(), 2 * 4 * 3);
+        assert_eq!(exemplars::<[bool; 3]>().len(), 8);
+        assert_eq!(exemplars::<[Ordering; 0]>().len(), 1);
+        let values = exemplars::<(i8, Ordering, bool)>();
+        assert_eq!(values.len(), 255 * 3 * 2);
+        assert_eq!(values.iter().collect::<BTreeSet<_>>().len(), values.len());
+    }
+
+    /// Checks that `exemplar_at` agrees with walking the stream, up to
+    /// `limit` positions, and that it ends where the stream ends.
+    fn check_indexed<T: IndexedExemplars + PartialEq + core::fmt::Debug>(limit: usize) {
+        let values: Vec<T> = T::exemplars().into_iter().take(limit).collect();
+        for (index, value) in values.iter().enumerate() {
+            assert_eq!(T::exemplar_at(index).as_ref(), Some(value), "#{index}");
+        }
+        if values.len() < limit {
+            assert_eq!(T::len(), Some(values.len()));
+            assert_eq!(T::exemplar_at(values.len()), None);
+            assert_eq!(T::exemplar_at(usize::MAX), None);
+        }
+    }
+
+    #[test]
+    fn unrank_matches_walk() {
+        for lens in [[1, 1, 1], [2, 3, 1], [3, 1, 4], [5, 2, 1], [1, 6, 2]] {
+            let vectors = walk(lens.map(Some), usize::MAX);
+            for (index, vector) in vectors.iter().enumerate() {
+                let lens = lens.map(|len| Some(len as u128));
+                let vector = vector.map(|i| i as u128);
+                assert_eq!(unrank(lens, index as u128), Some(vector), "{lens:?}");
+            }
+            let lens = lens.map(|len| Some(len as u128));
+            assert_eq!(unrank(lens, vectors.len() as u128), None, "{lens:?}");
+        }
+        let lens = [None, Some(2), Some(1), None];
+        for (index, vector) in walk(lens, 500).into_iter().enumerate() {
+            let lens = lens.map(|len| len.map(|len| len as u128));
+            let vector = vector.map(|i| i as u128);
+            assert_eq!(unrank(lens, index as u128), Some(vector), "{lens:?}");
+        }
+        assert_eq!(unrank::<0>([], 0), Some([]));
+        assert_eq!(unrank::<0>([], 1), None);
+        assert_eq!(unrank([Some(0), None], 0), None);
+    }
+
+    #[test]
+    fn tuples_index_like_their_stream() {
+        check_indexed::<((),)>(10);
+        check_indexed::<(bool, ())>(10);
+        check_indexed::<((), bool, Option<bool>)>(10);
+        check_indexed::<(u8, bool)>(1000);
+        check_indexed::<(bool, u8, ())>(1000);
+        check_indexed::<(u128, bool)>(500);
+        check_indexed::<(bool, u128, (), u64)>(500);
+        check_indexed::<(Option<bool>, i8)>(1000);
+        check_indexed::<(char, u8)>(3000);
+        check_indexed::<(f64, bool)>(100);
+    }
+
+    #[test]
+    fn arrays_index_like_their_stream() {
+        check_indexed::<[bool; 0]>(10);
+        check_indexed::<[u128; 0]>(10);
+        check_indexed::<[(); 3]>(10);
+        check_indexed::<[bool; 3]>(10);
+        check_indexed::<[Option<bool>; 2]>(10);
+        check_indexed::<[u8; 2]>(500);
+        check_indexed::<[u128; 3]>(500);
+        check_indexed::<[(bool, u64); 2]>(500);
+        check_indexed::<([bool; 2], [(); 2], u128)>(500);
+        check_indexed::<[char; 2]>(200);
+    }
+}
@@ -0,0 +1,189 @@
+//! Seeded sampling from exemplar streams.
+
+use crate::{Exemplars, IndexedExemplars};
+
+/// The SplitMix64 generator, which is plenty for picking positions.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+
+    /// A position below `len`, picking its bit width uniformly first.
+    fn position(&mut self, len: u128) -> u128 {
+        let last = len - 1;
+        let widths = u64::from(u128::BITS - last.leading_zeros()) + 1;
+        let width = (self.next_u64() % widths) as u32;
+        let mask = u128::MAX.checked_shr(u128::BITS - width).unwrap_or(0);
+        loop {
+            let position = self.next_u128() & mask;
+            if position <= last {
+                return position;
+            }
+        }
+    }
+}
+
+/// Draw `n` exemplars of `T` from across its whole stream.
+///
+/// Positions are picked by a small built-in generator, so the same `seed`
+/// always gives the same values. Every bit width of the position is equally
+/// likely, so a huge stream like that of `u128` yields small and large values
+/// alike instead of only the first few. Values are looked up directly with
+/// `exemplar_at_u128`.
+///
+/// Positions are `u128` values, so the product of several streams as long as
+/// that of `u128` only reaches the values at its first `u128::MAX` positions.
+/// Streams of unknown length are sampled as if they had `u128::MAX` values.
+/// Positions past their end are skipped, so fewer than `n` values may come out
+/// for such streams.
+///
+/// Types without `IndexedExemplars` can use [`sample_by_walking`] instead.
+pub fn sample<T: IndexedExemplars>(seed: u64, n: usize) -> impl Iterator<Item = T> {
+    let mut rng = SplitMix64(seed);
+    let len = T::len_u128().unwrap_or(u128::MAX);
+    (0..n).filter_map(move |_| {
+        if len == 0 {
+            return None;
+        }
+        T::exemplar_at_u128(rng.position(len))
+    })
+}
+
+/// Draw `n` exemplars of `T` from among its first `limit` ones.
+///
+/// Works like [`sample`] for any `Exemplars` type, but reaches each value by
+/// walking the stream from its start, so a single draw may take up to `limit`
+/// steps. Positions are picked below `limit`, or below the length of the
+/// stream when `CARDINALITY` gives it. Positions past the end of the stream
+/// are skipped, so fewer than `n` values may come out.
+///
+/// ```
+/// let names: Vec<String> = exemplars::sample_by_walking(7, 5, 100).collect();
+/// assert_eq!(names.len(), 5);
+/// ```
+pub fn sample_by_walking<T: Exemplars>(
+    seed: u64,
+    n: usize,
+    limit: usize,
+) -> impl Iterator<Item = T> {
+    let mut rng = SplitMix64(seed);
+    let len = T::CARDINALITY.finite().map_or(limit, |len| len.min(limit));
+    (0..n).filter_map(move |_| {
+        if len == 0 {
+            return None;
+        }
+        let position = rng.position(len as u128) as usize;
+        T::exemplars().into_iter().nth(position)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let a: Vec<u64> = sample(42, 50).collect();
+        let b: Vec<u64> = sample(42, 50).collect();
+        let c: Vec<u64> = sample(43, 50).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.len(), 50);
+    }
+
+    #[test]
+    fn wide_integers_reach_every_bit_width() {
+        let values: Vec<u128> = sample(1, 1000).collect();
+        assert_eq!(values.len(), 1000);
+        assert!(values.iter().any(|&x| x > u128::MAX >> 1));
+        assert!(values.iter().any(|&x| x > u64::MAX.into() && x < 1 << 96));
+        assert!(values.iter().any(|&x| x < 100));
+
+        let values: Vec<i128> = sample(1, 1000).collect();
+        assert!(values.iter().any(|&x| x < i64::MIN.into()));
+        assert!(values.iter().any(|&x| x > i64::MAX.into()));
+    }
+
+    #[test]
+    fn wide_composites_reach_every_bit_width() {
+        let wide = |x: u128| x > u64::MAX.into();
+
+        let values: Vec<Option<u128>> = sample(1, 2000).collect();
+        assert_eq!(values.len(), 2000);
+        assert!(values.iter().flatten().any(|&x| wide(x)));
+
+        let values: Vec<(u128, bool)> = sample(1, 2000).collect();
+        assert_eq!(values.len(), 2000);
+        assert!(values.iter().any(|&(x, _)| wide(x)));
+        assert!(values.iter().any(|&(_, b)| !b));
+
+        let values: Vec<[u64; 2]> = sample(1, 2000).collect();
+        assert_eq!(values.len(), 2000);
+        assert!(values.iter().any(|&[a, b]| a > 1 << 40 && b > 1 << 40));
+
+        let values: Vec<(u64, u64)> = sample(1, 2000).collect();
+        assert_eq!(values.len(), 2000);
+        assert!(values.iter().any(|&(a, b)| a > 1 << 40 && b > 1 << 40));
+    }
+
+    #[test]
+    fn samples_are_exemplars() {
+        let all: Vec<(char, u8)> = <(char, u8)>::exemplars().into_iter().collect();
+        let values: Vec<(char, u8)> = sample(3, 200).collect();
+        assert_eq!(values.len(), 200);
+        assert!(values.iter().all(|value| all.contains(value)));
+
+        let values: Vec<(f64, bool)> = sample(3, 200).collect();
+        assert_eq!(values.len(), 200);
+        assert!(values.iter().all(|value| value.0 != 0.0));
+    }
+
+    #[test]
+    fn single_and_empty_ranges() {
+        assert!(sample::<[u8; 0]>(0, 5).eq([[]; 5]));
+        assert_eq!(sample_by_walking::<bool>(0, 5, 0).count(), 0);
+    }
+
+    #[test]
+    fn walking_stays_within_the_limit() {
+        let first: Vec<(u8, char)> = <(u8, char)>::exemplars().into_iter().take(30).collect();
+        let values: Vec<(u8, char)> = sample_by_walking(5, 100, 30).collect();
+        assert_eq!(values.len(), 100);
+        assert!(values.iter().all(|value| first.contains(value)));
+        assert!(values.iter().any(|value| *value != first[0]));
+    }
+
+    #[test]
+    fn walking_uses_the_cardinality() {
+        let values: Vec<Option<bool>> = sample_by_walking(9, 100, usize::MAX).collect();
+        assert_eq!(values.len(), 100);
+        for value in [Some(true), Some(false), None] {
+            assert!(values.contains(&value));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn walking_works_for_unindexed_types() {
+        use alloc::string::String;
+
+        let values: Vec<String> = sample_by_walking(11, 20, 50).collect();
+        assert_eq!(values.len(), 20);
+        let values: Vec<Result<char, f32>> = sample_by_walking(11, 20, 50).collect();
+        assert_eq!(values.len(), 20);
+    }
+}
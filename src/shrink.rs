@@ -0,0 +1,284 @@
+//! Shrinking failing values toward the primary exemplar.
+
+/// A trait for listing simpler versions of a value.
+///
+/// Simpler means closer to the primary exemplar of the type: numbers step
+/// toward 1, collections get shorter and so on.
+pub trait Shrink: Sized {
+    /// Iterate over simpler versions of `self`, most promising first.
+    ///
+    /// Shrinking a candidate again must eventually run out, so that
+    /// [`minimize`] always ends.
+    fn shrink(&self) -> impl Iterator<Item = Self>;
+}
+
+/// Shrink a failing value for as long as it keeps failing.
+///
+/// Replaces `value` with the first of its simpler versions for which `fails`
+/// returns `true`, and repeats until none of them fail. Returns the last
+/// failing value.
+pub fn minimize<T: Shrink>(mut value: T, mut fails: impl FnMut(&T) -> bool) -> T {
+    loop {
+        let Some(simpler) = value.shrink().find(&mut fails) else {
+            return value;
+        };
+        value = simpler;
+    }
+}
+
+impl Shrink for () {
+    fn shrink(&self) -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> impl Iterator<Item = Self> {
+        (!*self).then_some(true).into_iter()
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> impl Iterator<Item = Self> {
+        (*self != 'a').then_some('a').into_iter()
+    }
+}
+
+/// Implement `Shrink` for unsigned number types, moving toward 1.
+///
+/// Candidates are 1, then values halfway, a quarter of the way and so on
+/// from `self` to 1. Zero shrinks to 1.
+macro_rules! impl_for_unsigned_number_types {
+    ($($t:ident),+) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> impl Iterator<Item = Self> {
+                let value = *self;
+                let one = (value == 0).then_some(1);
+                let distance = value.saturating_sub(1);
+                let distances = core::iter::successors(Some(distance), |d| Some(d / 2))
+                    .take_while(|&d| d > 0);
+                one.into_iter().chain(distances.map(move |d| value - d))
+            }
+        }
+    )+}
+}
+impl_for_unsigned_number_types!(usize, u8, u16, u32, u64, u128);
+
+/// Implement `Shrink` for signed number types, moving toward 1.
+///
+/// Negative values try 1 and their positive counterpart first, then move
+/// toward -1 the same way unsigned values move toward 1.
+macro_rules! impl_for_signed_number_types {
+    ($($t:ident),+) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> impl Iterator<Item = Self> {
+                let value = *self;
+                let (first, target) = match value {
+                    0 => ([Some(1), None], 1),
+                    1.. => ([None, None], 1),
+                    ..0 => ([Some(1), value.checked_neg().filter(|&n| n != 1)], -1),
+                };
+                let distance = if value == 0 { 0 } else { value.abs_diff(target) };
+                let distances = core::iter::successors(Some(distance), |d| Some(d / 2))
+                    .take_while(|&d| d > 0);
+                first.into_iter().flatten().chain(distances.map(move |d| {
+                    if value < 0 {
+                        value + d as $t
+                    } else {
+                        value - d as $t
+                    }
+                }))
+            }
+        }
+    )+}
+}
+impl_for_signed_number_types!(isize, i8, i16, i32, i64, i128);
+
+/// Implement `Shrink` for floating point types, moving toward 1.0.
+///
+/// Candidates are 1.0, the value rounded toward zero, the value halfway to
+/// 1.0 and the value one closer to 1.0, keeping only those that are closer to
+/// 1.0 than `self`. NaN shrinks to 1.0.
+macro_rules! impl_for_float_types {
+    ($($t:ident),+) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> impl Iterator<Item = Self> {
+                let value = *self;
+                let distance = if value.is_nan() {
+                    $t::INFINITY
+                } else {
+                    (value - 1.0).abs()
+                };
+                let step = value - (value - 1.0).signum();
+                [1.0, value - value % 1.0, (value + 1.0) / 2.0, step]
+                    .into_iter()
+                    .filter(move |&candidate| (candidate - 1.0).abs() < distance)
+            }
+        }
+    )+}
+}
+impl_for_float_types!(f32, f64);
+
+/// Only the `Some` payload shrinks. `None` is never a candidate, as it comes
+/// after every `Some` value in the exemplars.
+impl<T: Shrink> Shrink for Option<T> {
+    fn shrink(&self) -> impl Iterator<Item = Self> {
+        self.iter().flat_map(T::shrink).map(Some)
+    }
+}
+
+/// Implement `Shrink` for tuples, shrinking one component at a time.
+macro_rules! impl_for_tuples {
+    ($(($($t:ident $i:tt),+);)+) => {$(
+        impl<$($t: Shrink + Clone),+> Shrink for ($($t,)+) {
+            fn shrink(&self) -> impl Iterator<Item = Self> {
+                core::iter::empty()
+                    $(.chain(self.$i.shrink().map(move |simpler| {
+                        let mut tuple = self.clone();
+                        tuple.$i = simpler;
+                        tuple
+                    })))+
+            }
+        }
+    )+}
+}
+impl_for_tuples! {
+    (A 0);
+    (A 0, B 1);
+    (A 0, B 1, C 2);
+    (A 0, B 1, C 2, D 3);
+    (A 0, B 1, C 2, D 3, E 4);
+    (A 0, B 1, C 2, D 3, E 4, F 5);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+    use core::ops::Range;
+
+    use super::Shrink;
+
+    /// Runs of positions to remove from a sequence of length `len`, largest
+    /// first, always leaving at least one element.
+    ///
+    /// Runs are aligned to the end, so the first element is kept longest.
+    fn removals(len: usize) -> impl Iterator<Item = Range<usize>> {
+        let halves = core::iter::successors(Some(len / 2), |size| Some(size / 2))
+            .take_while(move |&size| size > 0 && size < len - 1);
+        (len > 1)
+            .then(|| len - 1)
+            .into_iter()
+            .chain(halves)
+            .flat_map(move |size| {
+                (1..=len / size).map(move |run| len - size * run..len - size * (run - 1))
+            })
+    }
+
+    /// Shorter vectors first, then vectors with one element shrunk.
+    impl<T: Shrink + Clone> Shrink for Vec<T> {
+        fn shrink(&self) -> impl Iterator<Item = Self> {
+            let shorter = removals(self.len()).map(move |run| {
+                let mut vec = self.clone();
+                vec.drain(run);
+                vec
+            });
+            let simpler = self.iter().enumerate().flat_map(move |(i, element)| {
+                element.shrink().map(move |simpler| {
+                    let mut vec = self.clone();
+                    vec[i] = simpler;
+                    vec
+                })
+            });
+            shorter.chain(simpler)
+        }
+    }
+
+    /// Shorter strings, keeping at least one character.
+    impl Shrink for String {
+        fn shrink(&self) -> impl Iterator<Item = Self> {
+            let chars: Vec<char> = self.chars().collect();
+            removals(chars.len()).map(move |run| {
+                let mut chars = chars.clone();
+                chars.drain(run);
+                chars.into_iter().collect()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_move_toward_one() {
+        assert_eq!(minimize(200u8, |x| *x > 10), 11);
+        assert_eq!(minimize(0u8, |_| true), 1);
+        assert_eq!(minimize(u128::MAX, |_| true), 1);
+        assert_eq!(minimize(i8::MIN, |x| *x < 0), -1);
+        assert_eq!(minimize(i8::MIN, |x| *x < -50), -51);
+        assert_eq!(minimize(i64::MIN, |_| true), 1);
+        assert_eq!(minimize(-7i32, |x| x.abs() > 3), 4);
+        assert_eq!(minimize(0i16, |_| true), 1);
+    }
+
+    #[test]
+    fn floats_move_toward_one() {
+        for value in [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::MAX,
+            -0.0,
+            1e-300,
+        ] {
+            assert_eq!(minimize(value, |_| true), 1.0, "{value}");
+        }
+        assert_eq!(minimize(f32::NAN, |_| true), 1.0);
+        assert_eq!(minimize(f32::INFINITY, |_| true), 1.0);
+        assert_eq!(minimize(123.456f64, |x| *x > 10.0), 11.0);
+    }
+
+    #[test]
+    fn already_minimal_values_do_not_shrink() {
+        assert_eq!(1u8.shrink().count(), 0);
+        assert_eq!(1i8.shrink().count(), 0);
+        assert_eq!(1.0f64.shrink().count(), 0);
+        assert_eq!(true.shrink().count(), 0);
+        assert_eq!('a'.shrink().count(), 0);
+        assert_eq!(None::<u8>.shrink().count(), 0);
+    }
+
+    #[test]
+    fn composites_shrink_their_parts() {
+        assert_eq!(minimize(Some(100u8), |_| true), Some(1));
+        assert_eq!(
+            minimize((10u8, false, 'x'), |(a, ..)| *a > 3),
+            (4, true, 'a')
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequences_keep_one_element() {
+        extern crate alloc;
+        use alloc::{string::String, vec, vec::Vec};
+
+        assert_eq!(minimize(vec![5u8, 6, 7, 8, 9], |_| true), [1]);
+        assert_eq!(minimize(vec![5u8, 6, 7], |v| v.contains(&7)), [7]);
+        assert_eq!(minimize(Vec::<u8>::new(), |_| true), []);
+        assert_eq!(minimize(String::from("hello"), |_| true), "h");
+        assert_eq!(minimize(String::from("hello"), |s| s.contains('l')), "l");
+        assert_eq!(minimize(String::new(), |_| true), "");
+        assert!(vec![3u8].shrink().all(|v| v.len() == 1));
+        assert!(String::from("x").shrink().all(|s| !s.is_empty()));
+    }
+}
@@ -0,0 +1,75 @@
+//! Strings for testing text handling.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use crate::{Cardinality, Exemplars};
+
+/// A string drawn from a set of adversarial values.
+///
+/// The exemplars are strings that tend to break parsers and user interfaces:
+/// whitespace only, combining characters, right-to-left text, emoji ZWJ
+/// sequences, embedded NUL, very long lines, CRLF line endings, SQL and HTML
+/// metacharacters and text that is not in Unicode normalization form C. Use
+/// this type instead of `String` where the inputs should be hostile rather
+/// than typical.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tricky(pub String);
+
+impl Exemplars for Tricky {
+    const CARDINALITY: Cardinality = Cardinality::Finite(23);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [
+            // Whitespace only.
+            " ".into(),
+            " \t\r\n".into(),
+            "\u{a0}\u{2003}\u{3000}".into(),
+            // Combining characters.
+            "e\u{301}\u{302}\u{303}\u{304}".into(),
+            "Z\u{351}\u{36b}\u{343}\u{36a}a\u{302}\u{36b}l\u{352}g\u{34c}o\u{35a}".into(),
+            // Right-to-left text and direction overrides.
+            "\u{5e9}\u{5dc}\u{5d5}\u{5dd}".into(),
+            "\u{645}\u{631}\u{62d}\u{628}\u{627} hello".into(),
+            "invoice\u{202e}fdp.exe".into(),
+            // Emoji ZWJ sequences, flags and modifiers.
+            "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}".into(),
+            "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}".into(),
+            "\u{1f1fa}\u{1f1f3}\u{1f44d}\u{1f3fd}".into(),
+            // Control characters.
+            "nul\0byte".into(),
+            "\u{7}\u{8}\u{1b}[31mred\u{1b}[0m".into(),
+            // Line endings.
+            "first line\r\nsecond line\r\n".into(),
+            "lone\rcarriage return".into(),
+            // A very long line.
+            "long ".repeat(4096),
+            // SQL metacharacters.
+            "'; DROP TABLE users; --".into(),
+            "\" OR 1=1 --".into(),
+            // HTML metacharacters.
+            "<script>alert(1)</script>".into(),
+            "&amp; &lt;b&gt; \"double\" 'single'".into(),
+            // Not in normalization form C.
+            "e\u{301}".into(),
+            "\u{212b}ngstr\u{f6}m".into(),
+            "\u{1e0b}\u{323}".into(),
+        ]
+        .map(Self)
+    }
+}
+
+impl core::ops::Deref for Tricky {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Tricky> for String {
+    fn from(value: Tricky) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::{CStr, CString, OsString},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::{NonZeroI128, NonZeroI8, NonZeroU16, NonZeroU64, NonZeroU8},
+    ops::{Bound, ControlFlow},
+    path::PathBuf,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, AtomicI32, AtomicU8},
+        Arc, Mutex, RwLock,
+    },
+    task::Poll,
+    time::{Duration, SystemTime},
+};
+
+use exemplars::{check_impl, check_impl_eq, strings::Tricky, Exemplars};
+
+/// Run the full check on each type.
+macro_rules! assert_ok_eq {
+    ($($t:ty),+ $(,)?) => {$(
+        check_impl_eq::<$t>().assert_ok();
+    )+}
+}
+
+/// Run the checks that need no `PartialEq` on each type.
+macro_rules! assert_ok {
+    ($($t:ty),+ $(,)?) => {$(
+        check_impl::<$t>().assert_ok();
+    )+}
+}
+
+#[test]
+fn primitives() {
+    assert_ok_eq!(
+        (),
+        bool,
+        char,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+        f32,
+        f64,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU64,
+        NonZeroI8,
+        NonZeroI128,
+    );
+}
+
+#[test]
+fn core_types() {
+    assert_ok_eq!(
+        Option<bool>,
+        Option<u8>,
+        Option<Option<()>>,
+        Result<bool, Ordering>,
+        Result<u8, char>,
+        Result<u8, ()>,
+        Ordering,
+        Bound<bool>,
+        ControlFlow<bool, char>,
+        Poll<Ordering>,
+        Cell<bool>,
+        RefCell<u8>,
+        Duration,
+    );
+    assert_ok!(AtomicBool, AtomicU8, AtomicI32);
+}
+
+#[test]
+fn tuples_and_arrays() {
+    assert_ok_eq!(
+        (bool,),
+        (bool, char),
+        (u8, Ordering, ()),
+        (bool, u128, Option<bool>),
+        (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+        [bool; 0],
+        [bool; 3],
+        [Ordering; 4],
+        [u8; 2],
+        [(); 5],
+    );
+}
+
+#[test]
+fn net_types() {
+    assert_ok_eq!(
+        Ipv4Addr,
+        Ipv6Addr,
+        IpAddr,
+        SocketAddrV4,
+        SocketAddrV6,
+        SocketAddr,
+    );
+}
+
+#[test]
+fn alloc_types() {
+    assert_ok_eq!(
+        String,
+        Vec<bool>,
+        Vec<u8>,
+        Vec<()>,
+        VecDeque<bool>,
+        LinkedList<u8>,
+        BTreeSet<()>,
+        BTreeSet<bool>,
+        BTreeSet<Ordering>,
+        BTreeSet<u8>,
+        BTreeMap<bool, bool>,
+        BTreeMap<Ordering, ()>,
+        CString,
+        Box<CStr>,
+        Box<bool>,
+        Box<str>,
+        Box<[bool]>,
+        Rc<Ordering>,
+        Arc<char>,
+        Cow<'static, str>,
+        Cow<'static, [bool]>,
+        Tricky,
+    );
+    assert_ok!(BinaryHeap<bool>, BinaryHeap<u8>);
+}
+
+#[test]
+fn std_types() {
+    assert_ok_eq!(
+        HashSet<bool>,
+        HashSet<Ordering>,
+        HashMap<bool, Ordering>,
+        OsString,
+        PathBuf,
+        SystemTime,
+    );
+    assert_ok!(Mutex<bool>, RwLock<Ordering>);
+}
+
+#[test]
+fn references() {
+    assert_ok_eq!(
+        &'static str,
+        &'static [u8],
+        &'static [bool],
+        &'static CStr,
+        &'static u16,
+        &'static i64,
+    );
+}
+
+#[test]
+fn optional_types() {
+    #[cfg(feature = "bigdecimal_03")]
+    assert_ok_eq!(bigdecimal_03::BigDecimal);
+    #[cfg(feature = "bigdecimal_04")]
+    assert_ok_eq!(bigdecimal_04::BigDecimal);
+    #[cfg(feature = "rust_decimal")]
+    assert_ok_eq!(rust_decimal::Decimal);
+    #[cfg(feature = "uuid")]
+    assert_ok_eq!(uuid::Uuid);
+}
+
+struct Repeats;
+
+impl Exemplars for Repeats {
+    const CARDINALITY: exemplars::Cardinality = exemplars::Cardinality::Finite(2);
+
+    fn exemplars() -> impl IntoIterator<Item = Self> {
+        [Repeats, Repeats, Repeats]
+    }
+}
+
+impl std::fmt::Debug for Repeats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Repeats")
+    }
+}
+
+impl PartialEq for Repeats {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn problems_are_reported() {
+    let report = check_impl::<Repeats>();
+    assert_eq!(
+        report.problems(),
+        ["CARDINALITY is Finite(2) but exemplars() returned 3 values"]
+    );
+    let report = check_impl_eq::<Repeats>();
+    assert_eq!(report.problems().len(), 3, "{report}");
+    assert!(report.problems()[1..]
+        .iter()
+        .all(|problem| problem.ends_with("are both Repeats")));
+}
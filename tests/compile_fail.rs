@@ -0,0 +1,4 @@
+#[test]
+fn macro_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
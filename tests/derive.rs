@@ -0,0 +1,242 @@
+use exemplars::{Cardinality, Exemplars};
+
+fn exemplars<T: Exemplars>(n: usize) -> Vec<T> {
+    T::exemplars().into_iter().take(n).collect()
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+enum Shape {
+    Point,
+    Circle(u8),
+    Rect { wide: bool, tall: bool },
+}
+
+#[test]
+fn enum_variants_take_turns() {
+    assert_eq!(
+        exemplars::<Shape>(7),
+        [
+            Shape::Point,
+            Shape::Circle(1),
+            Shape::Rect {
+                wide: true,
+                tall: true
+            },
+            Shape::Circle(2),
+            Shape::Rect {
+                wide: true,
+                tall: false
+            },
+            Shape::Circle(3),
+            Shape::Rect {
+                wide: false,
+                tall: true
+            },
+        ]
+    );
+    assert_eq!(Shape::exemplar(), Shape::Point);
+    assert_eq!(Shape::CARDINALITY, Cardinality::Finite(1 + 255 + 4));
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+enum Level {
+    Low,
+    #[exemplars(primary)]
+    High,
+    Mid,
+}
+
+#[test]
+fn enum_primary_variant_comes_first() {
+    assert_eq!(
+        exemplars::<Level>(10),
+        [Level::High, Level::Low, Level::Mid]
+    );
+    assert_eq!(Level::exemplar(), Level::High);
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+enum Tree {
+    Leaf(bool),
+    #[exemplars(skip)]
+    #[allow(dead_code)]
+    Node(Box<Tree>, Box<Tree>),
+}
+
+#[test]
+fn enum_skipped_variants_are_never_produced() {
+    assert_eq!(exemplars::<Tree>(10), [Tree::Leaf(true), Tree::Leaf(false)]);
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+enum Wrapper<T> {
+    Empty,
+    Full(T),
+}
+
+#[test]
+fn enum_generic_payloads() {
+    assert_eq!(
+        exemplars::<Wrapper<bool>>(10),
+        [Wrapper::Empty, Wrapper::Full(true), Wrapper::Full(false)]
+    );
+}
+
+fn small() -> impl IntoIterator<Item = u8> {
+    [7, 8]
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Values(#[exemplars(values = [3, 5])] u8);
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct With(#[exemplars(with = small)] u8);
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Primary(bool, #[exemplars(primary = 42)] u8);
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct ValuesPrimary(#[exemplars(values = [3, 5], primary = 5)] u8);
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct WithPrimary(#[exemplars(with = small, primary = 9)] u8);
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Mixed {
+    #[exemplars(values = [10, 20, 30])]
+    a: u8,
+    b: bool,
+}
+
+#[test]
+fn field_values() {
+    assert_eq!(exemplars::<Values>(10), [Values(3), Values(5)]);
+    assert_eq!(Values::exemplar(), Values(3));
+    assert_eq!(Values::CARDINALITY, Cardinality::Finite(2));
+}
+
+#[test]
+fn field_with() {
+    assert_eq!(exemplars::<With>(10), [With(7), With(8)]);
+    assert_eq!(With::exemplar(), With(7));
+    assert_eq!(With::CARDINALITY, Cardinality::AtLeast(1));
+}
+
+#[test]
+fn field_primary_is_not_repeated() {
+    let values = exemplars::<Primary>(300);
+    assert_eq!(values[0], Primary(true, 42));
+    assert_eq!(values[1], Primary(true, 1));
+    assert_eq!(values[2], Primary(false, 42));
+    let values = exemplars::<Primary>(1000);
+    assert_eq!(values.len(), 2 * 255);
+    assert_eq!(values.iter().filter(|value| value.1 == 42).count(), 2);
+    assert_eq!(Primary::exemplar(), Primary(true, 42));
+    assert_eq!(Primary::CARDINALITY, Cardinality::AtLeast(2 * 255));
+}
+
+#[test]
+fn field_values_and_primary() {
+    assert_eq!(
+        exemplars::<ValuesPrimary>(10),
+        [ValuesPrimary(5), ValuesPrimary(3)]
+    );
+    assert_eq!(ValuesPrimary::exemplar(), ValuesPrimary(5));
+    assert_eq!(ValuesPrimary::CARDINALITY, Cardinality::AtLeast(2));
+}
+
+#[test]
+fn field_with_and_primary() {
+    assert_eq!(
+        exemplars::<WithPrimary>(10),
+        [WithPrimary(9), WithPrimary(7), WithPrimary(8)]
+    );
+    assert_eq!(WithPrimary::exemplar(), WithPrimary(9));
+}
+
+#[test]
+fn field_streams_are_combined() {
+    assert_eq!(
+        exemplars::<Mixed>(10),
+        [
+            Mixed { a: 10, b: true },
+            Mixed { a: 10, b: false },
+            Mixed { a: 20, b: true },
+            Mixed { a: 20, b: false },
+            Mixed { a: 30, b: true },
+            Mixed { a: 30, b: false },
+        ]
+    );
+    assert_eq!(Mixed::CARDINALITY, Cardinality::Finite(6));
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Rect {
+    wide: bool,
+    tall: bool,
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Triple(u64, bool, Option<bool>);
+
+#[test]
+fn structs_enumerate_like_tuples() {
+    let rects: Vec<(bool, bool)> = exemplars::<Rect>(10)
+        .into_iter()
+        .map(|Rect { wide, tall }| (wide, tall))
+        .collect();
+    assert_eq!(rects, exemplars::<(bool, bool)>(10));
+    assert_eq!(rects.len(), 4);
+    assert_eq!(Rect::CARDINALITY, Cardinality::Finite(4));
+
+    let values: Vec<(u64, bool, Option<bool>)> = exemplars::<Triple>(100)
+        .into_iter()
+        .map(|Triple(a, b, c)| (a, b, c))
+        .collect();
+    assert_eq!(values, exemplars::<(u64, bool, Option<bool>)>(100));
+    assert!(values.contains(&(1, false, None)));
+    assert_eq!(
+        Triple::CARDINALITY,
+        <(u64, bool, Option<bool>)>::CARDINALITY
+    );
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Contact {
+    #[exemplars(values = [String::from("a@example.com"), String::from("b@example.com")])]
+    email: String,
+    #[exemplars(primary = String::from("Ada"))]
+    name: String,
+}
+
+#[test]
+fn field_values_of_owned_types() {
+    let contact = Contact::exemplar();
+    assert_eq!(contact.email, "a@example.com");
+    assert_eq!(contact.name, "Ada");
+    let contacts = exemplars::<Contact>(100);
+    assert_eq!(
+        contacts
+            .iter()
+            .filter(|contact| contact.name == "Ada")
+            .count(),
+        2
+    );
+}
+
+static EVALUATIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn counted(value: u8) -> u8 {
+    EVALUATIONS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    value
+}
+
+#[derive(Debug, PartialEq, Exemplars)]
+struct Counted(#[exemplars(primary = counted(9))] u8, bool);
+
+#[test]
+fn field_primary_is_evaluated_once() {
+    let values = exemplars::<Counted>(50);
+    assert_eq!(values[0], Counted(9, true));
+    assert_eq!(EVALUATIONS.load(std::sync::atomic::Ordering::Relaxed), 1);
+}
@@ -0,0 +1,86 @@
+use exemplars::EdgeCases;
+
+fn edge_cases<T: EdgeCases>() -> Vec<T> {
+    T::edge_cases().into_iter().collect()
+}
+
+#[test]
+fn unit_and_bool() {
+    assert_eq!(edge_cases::<()>(), []);
+    assert_eq!(edge_cases::<bool>(), [false]);
+}
+
+#[test]
+fn unsigned_integers() {
+    assert_eq!(edge_cases::<u8>(), [0, u8::MAX]);
+    assert_eq!(edge_cases::<u128>(), [0, u128::MAX]);
+    assert_eq!(edge_cases::<usize>(), [0, usize::MAX]);
+}
+
+#[test]
+fn signed_integers() {
+    assert_eq!(edge_cases::<i8>(), [0, -1, i8::MIN, i8::MAX]);
+    assert_eq!(edge_cases::<i32>(), [0, -1, i32::MIN, i32::MAX]);
+    assert_eq!(edge_cases::<i128>(), [0, -1, i128::MIN, i128::MAX]);
+    assert_eq!(edge_cases::<isize>(), [0, -1, isize::MIN, isize::MAX]);
+}
+
+#[test]
+fn non_zero_integers_leave_out_zero() {
+    use std::num::{NonZeroI8, NonZeroU8};
+
+    let values: Vec<u8> = edge_cases::<NonZeroU8>()
+        .into_iter()
+        .map(NonZeroU8::get)
+        .collect();
+    assert_eq!(values, [u8::MAX]);
+    let values: Vec<i8> = edge_cases::<NonZeroI8>()
+        .into_iter()
+        .map(NonZeroI8::get)
+        .collect();
+    assert_eq!(values, [-1, i8::MIN, i8::MAX]);
+}
+
+#[test]
+fn floats() {
+    let values = edge_cases::<f64>();
+    assert!(values.iter().any(|x| x.is_nan()));
+    assert!(values.contains(&f64::INFINITY));
+    assert!(values.contains(&f64::NEG_INFINITY));
+    assert!(values.iter().any(|x| *x == 0.0 && x.is_sign_negative()));
+    assert!(values.iter().any(|x| *x == 0.0 && x.is_sign_positive()));
+    assert!(values.iter().any(|x| x.is_subnormal()));
+
+    let values = edge_cases::<f32>();
+    assert!(values.iter().any(|x| x.is_nan()));
+    assert!(values.contains(&f32::INFINITY));
+    assert!(values.contains(&f32::NEG_INFINITY));
+    assert!(values.iter().any(|x| *x == 0.0 && x.is_sign_negative()));
+}
+
+#[test]
+fn char() {
+    let values = edge_cases::<char>();
+    assert_eq!(values[0], '\0');
+    assert!(values.contains(&char::MAX));
+}
+
+#[test]
+fn option_wraps_edge_cases_of_payload() {
+    assert_eq!(
+        edge_cases::<Option<i8>>(),
+        [None, Some(0), Some(-1), Some(i8::MIN), Some(i8::MAX)]
+    );
+    assert_eq!(edge_cases::<Option<()>>(), [None]);
+}
+
+#[test]
+fn sequences_start_empty() {
+    assert_eq!(edge_cases::<String>(), [""]);
+    assert_eq!(edge_cases::<Vec<u8>>(), [vec![], vec![0], vec![u8::MAX]]);
+    assert_eq!(edge_cases::<Vec<bool>>(), [vec![], vec![false]]);
+    assert_eq!(
+        edge_cases::<Vec<Option<bool>>>(),
+        [vec![], vec![None], vec![Some(false)]]
+    );
+}
@@ -0,0 +1,80 @@
+use std::{panic, sync::Mutex};
+
+use exemplars::{exemplar_test, Exemplars};
+
+static SINGLE: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+static PAIRS: Mutex<Vec<(bool, Option<bool>)>> = Mutex::new(Vec::new());
+static LIMITED: Mutex<Vec<i32>> = Mutex::new(Vec::new());
+
+// The tests below call these directly, so they are ignored by the harness.
+
+#[exemplar_test]
+#[ignore]
+fn single(x: u8) {
+    SINGLE.lock().unwrap().push(x);
+}
+
+#[exemplar_test]
+#[ignore]
+fn pairs(a: bool, b: Option<bool>) {
+    PAIRS.lock().unwrap().push((a, b));
+}
+
+#[exemplar_test(limit = 5)]
+#[ignore]
+fn limited(x: i32) {
+    LIMITED.lock().unwrap().push(x);
+}
+
+#[exemplar_test]
+#[ignore]
+fn fails_on_three(x: u8) {
+    assert_ne!(x, 3, "three is not allowed");
+}
+
+#[exemplar_test]
+#[ignore = "panics on every exemplar"]
+fn always_fails(_: bool) {
+    panic!("ignored tests must not run");
+}
+
+#[exemplar_test(limit = 3)]
+fn runs_on_its_own(x: u16, y: char) {
+    assert!(x >= 1);
+    assert!(!y.is_control() || y == '\n');
+}
+
+#[test]
+fn single_parameter_runs_default_limit() {
+    single();
+    let expected: Vec<u8> = u8::exemplars().into_iter().take(100).collect();
+    assert_eq!(*SINGLE.lock().unwrap(), expected);
+}
+
+#[test]
+fn several_parameters_run_over_the_tuple() {
+    pairs();
+    let expected: Vec<(bool, Option<bool>)> =
+        <(bool, Option<bool>)>::exemplars().into_iter().collect();
+    assert_eq!(expected.len(), 6);
+    assert_eq!(*PAIRS.lock().unwrap(), expected);
+}
+
+#[test]
+fn limit_caps_the_runs() {
+    limited();
+    assert_eq!(*LIMITED.lock().unwrap(), [1, -1, 2, -2, 3]);
+}
+
+#[test]
+fn failure_names_the_exemplar() {
+    let payload = panic::catch_unwind(fails_on_three).expect_err("3 is an exemplar of u8");
+    let message = payload
+        .downcast_ref::<String>()
+        .expect("the failure message is formatted");
+    assert!(
+        message.starts_with("test failed on exemplar #2: 3\n"),
+        "{message}"
+    );
+    assert!(message.contains("three is not allowed"), "{message}");
+}
@@ -0,0 +1,41 @@
+use exemplars::{Exemplars, IndexedExemplars};
+
+#[test]
+fn signed_exemplars_alternate_in_sign() {
+    let values: Vec<i8> = i8::exemplars().into_iter().collect();
+    assert_eq!(values[..6], [1, -1, 2, -2, 3, -3]);
+    assert_eq!(values[values.len() - 3..], [i8::MAX, -i8::MAX, i8::MIN]);
+    assert_eq!(values.len(), 255);
+    assert!(!values.contains(&0));
+    assert_eq!(i8::exemplar(), 1);
+
+    let values: Vec<i64> = i64::exemplars().into_iter().take(4).collect();
+    assert_eq!(values, [1, -1, 2, -2]);
+}
+
+#[test]
+fn signed_positions_match_the_stream() {
+    for (index, value) in i16::exemplars().into_iter().enumerate() {
+        assert_eq!(i16::exemplar_at(index), Some(value));
+    }
+    assert_eq!(i16::exemplar_at(u16::MAX as usize), None);
+}
+
+#[test]
+fn wide_signed_positions_end_with_min() {
+    assert_eq!(i128::len_u128(), Some(u128::MAX));
+    assert_eq!(i128::exemplar_at_u128(0), Some(1));
+    assert_eq!(i128::exemplar_at_u128(1), Some(-1));
+    assert_eq!(i128::exemplar_at_u128(u128::MAX - 3), Some(i128::MAX));
+    assert_eq!(i128::exemplar_at_u128(u128::MAX - 2), Some(-i128::MAX));
+    assert_eq!(i128::exemplar_at_u128(u128::MAX - 1), Some(i128::MIN));
+    assert_eq!(i128::exemplar_at_u128(u128::MAX), None);
+}
+
+#[test]
+fn wide_unsigned_positions_end_with_max() {
+    assert_eq!(u128::len_u128(), Some(u128::MAX));
+    assert_eq!(u128::exemplar_at_u128(0), Some(1));
+    assert_eq!(u128::exemplar_at_u128(u128::MAX - 1), Some(u128::MAX));
+    assert_eq!(u128::exemplar_at_u128(u128::MAX), None);
+}
@@ -0,0 +1,34 @@
+//! Kept apart from the other tests, since it changes the environment of the
+//! whole test binary.
+
+use std::{env, panic};
+
+use exemplars::check::{for_all, LIMIT_VAR};
+
+fn count(limit: usize) -> usize {
+    let mut count = 0;
+    for_all::<u32>(limit, |_| count += 1);
+    count
+}
+
+#[test]
+fn environment_overrides_the_limit() {
+    env::remove_var(LIMIT_VAR);
+    assert_eq!(count(5), 5);
+
+    env::set_var(LIMIT_VAR, "12");
+    assert_eq!(count(5), 12);
+    env::set_var(LIMIT_VAR, " 3 ");
+    assert_eq!(count(5), 3);
+
+    env::set_var(LIMIT_VAR, "many");
+    let payload = panic::catch_unwind(|| count(5)).expect_err("the limit is not a number");
+    let message = payload.downcast_ref::<String>().expect("formatted message");
+    assert_eq!(
+        message,
+        r#"invalid EXEMPLARS_LIMIT: "many" is not a number"#
+    );
+
+    env::remove_var(LIMIT_VAR);
+    assert_eq!(count(5), 5);
+}
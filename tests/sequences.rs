@@ -0,0 +1,58 @@
+use std::collections::{LinkedList, VecDeque};
+
+use exemplars::Exemplars;
+
+/// The lengths of the first `n` exemplars of `T`.
+fn lengths<T: Exemplars>(n: usize, len: fn(&T) -> usize) -> Vec<usize> {
+    T::exemplars()
+        .into_iter()
+        .take(n)
+        .map(|x| len(&x))
+        .collect()
+}
+
+#[test]
+fn vec_starts_with_the_primary_element() {
+    assert_eq!(Vec::<u8>::exemplar(), vec![u8::exemplar()]);
+    assert_eq!(Vec::<bool>::exemplar(), vec![true]);
+    assert_eq!(Vec::<Option<char>>::exemplar(), vec![Some('a')]);
+}
+
+#[test]
+fn vec_lengths_vary_early() {
+    let seen = lengths::<Vec<u8>>(100, Vec::len);
+    for len in 1..=10 {
+        assert!(seen.contains(&len), "no vector of length {len}");
+    }
+    // Elements with few exemplars leave fewer offsets, so lengths grow faster.
+    let seen = lengths::<Vec<bool>>(60, Vec::len);
+    for len in 1..=20 {
+        assert!(seen.contains(&len), "no vector of length {len}");
+    }
+}
+
+#[test]
+fn vec_elements_start_at_different_offsets() {
+    let values: Vec<Vec<u8>> = Vec::<u8>::exemplars().into_iter().take(100).collect();
+    assert!(values.contains(&vec![2]));
+    assert!(values.contains(&vec![3, 4]));
+    assert!(values.contains(&vec![1, 2, 3]));
+}
+
+#[test]
+fn string_lengths_vary_early() {
+    assert_eq!(String::exemplar(), "example");
+    let seen = lengths::<String>(100, |s| s.chars().count());
+    for len in 1..=10 {
+        assert!(seen.contains(&len), "no string of length {len}");
+    }
+}
+
+#[test]
+fn other_sequences_vary_too() {
+    assert_eq!(VecDeque::<u8>::exemplar(), VecDeque::from([1]));
+    let seen = lengths::<VecDeque<u8>>(100, VecDeque::len);
+    assert!((1..=10).all(|len| seen.contains(&len)));
+    let seen = lengths::<LinkedList<bool>>(60, LinkedList::len);
+    assert!((1..=20).all(|len| seen.contains(&len)));
+}
@@ -0,0 +1,28 @@
+use exemplars::{Exemplars, StaticExemplars};
+
+#[test]
+fn byte_slices_come_from_the_blanket_impl() {
+    let slices: Vec<&'static [u8]> = <&[u8]>::exemplars().into_iter().collect();
+    assert_eq!(slices, [[1].as_slice(), &[2], &[3]]);
+}
+
+#[test]
+fn integer_static_exemplars_match_their_exemplars() {
+    fn check<T: Exemplars + StaticExemplars + PartialEq + Copy + std::fmt::Debug>() {
+        let statics: Vec<T> = T::static_exemplars().into_iter().copied().collect();
+        let values: Vec<T> = T::exemplars().into_iter().take(statics.len()).collect();
+        assert_eq!(statics, values);
+    }
+    check::<u8>();
+    check::<u128>();
+    check::<usize>();
+    check::<i8>();
+    check::<i64>();
+    check::<isize>();
+}
+
+#[test]
+fn references_to_unsized_types() {
+    assert_eq!(<&str>::exemplar(), "example");
+    assert_eq!(<&[bool]>::exemplars().into_iter().count(), 2);
+}
@@ -0,0 +1,44 @@
+use exemplars::{strings::Tricky, Exemplars};
+
+fn tricky() -> Vec<String> {
+    Tricky::exemplars().into_iter().map(String::from).collect()
+}
+
+#[test]
+fn holds_embedded_nul() {
+    assert!(tricky().iter().any(|s| s.contains('\0') && s.len() > 1));
+}
+
+#[test]
+fn holds_crlf_line_endings() {
+    assert!(tricky().iter().any(|s| s.contains("\r\n")));
+}
+
+#[test]
+fn holds_text_not_in_nfc() {
+    // A base letter followed by a combining accent composes under NFC, and
+    // the Angstrom sign is replaced by the letter Å.
+    let strings = tricky();
+    assert!(strings.iter().any(|s| s.contains("e\u{301}")));
+    assert!(strings.iter().any(|s| s.contains('\u{212b}')));
+}
+
+#[test]
+fn holds_a_long_line() {
+    assert!(tricky()
+        .iter()
+        .any(|s| s.lines().any(|line| line.len() >= 4096)));
+}
+
+#[test]
+fn holds_whitespace_only_and_right_to_left_text() {
+    let strings = tricky();
+    assert!(strings.iter().any(|s| s.trim().is_empty()));
+    assert!(strings.iter().any(|s| s.contains('\u{202e}')));
+}
+
+#[test]
+fn derefs_to_str() {
+    let first = Tricky::exemplar();
+    assert_eq!(&*first, " ");
+}
@@ -0,0 +1,11 @@
+use exemplars::Exemplars;
+
+#[derive(Exemplars)]
+enum Level {
+    #[exemplars(skip)]
+    Low,
+    #[exemplars(skip)]
+    High,
+}
+
+fn main() {}
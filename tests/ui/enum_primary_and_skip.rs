@@ -0,0 +1,10 @@
+use exemplars::Exemplars;
+
+#[derive(Exemplars)]
+enum Level {
+    Low,
+    #[exemplars(primary, skip)]
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use exemplars::Exemplars;
+
+#[derive(Exemplars)]
+enum Level {
+    #[exemplars(primary)]
+    Low,
+    #[exemplars(primary)]
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use exemplars::Exemplars;
+
+#[derive(Exemplars)]
+enum Level {
+    #[exemplars(first)]
+    Low,
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use exemplars::Exemplars;
+
+#[derive(Exemplars)]
+struct User {
+    #[exemplars(primary = "Ada")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use exemplars::Exemplars;
+
+fn f() -> [u8; 1] {
+    [1]
+}
+
+#[derive(Exemplars)]
+struct Field(#[exemplars(value = 1)] u8);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use exemplars::Exemplars;
+
+fn f() -> [u8; 1] {
+    [1]
+}
+
+#[derive(Exemplars)]
+struct Field(#[exemplars(values = [1], with = f)] u8);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use exemplars::exemplar_test;
+
+#[exemplar_test]
+fn runs<T>(x: u8) {
+    let _ = x;
+}
+
+fn main() {}
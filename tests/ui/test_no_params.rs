@@ -0,0 +1,6 @@
+use exemplars::exemplar_test;
+
+#[exemplar_test]
+fn runs() {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use exemplars::exemplar_test;
+
+#[exemplar_test]
+fn runs(x: u8) -> bool {
+    x > 0
+}
+
+fn main() {}
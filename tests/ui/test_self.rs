@@ -0,0 +1,12 @@
+use exemplars::exemplar_test;
+
+struct Runner;
+
+impl Runner {
+    #[exemplar_test]
+    fn runs(self, x: u8) {
+        let _ = x;
+    }
+}
+
+fn main() {}